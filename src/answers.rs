@@ -0,0 +1,232 @@
+use std::{
+    collections::BTreeMap,
+    fmt::{Display, Formatter, Result as DisplayResult},
+    path::{Path, PathBuf},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{error::AdventError, store, RunResult};
+
+/// The answers of a day that Advent of Code accepted
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KnownAnswers {
+    pub silver: Option<String>,
+    pub gold: Option<String>,
+}
+
+impl KnownAnswers {
+    /// Read the accepted answers from a puzzle page, which shows them below every solved part
+    pub fn from_page(html: &str) -> KnownAnswers {
+        let document = scraper::Html::parse_document(html);
+        let paragraphs = scraper::Selector::parse("p").unwrap();
+        let code = scraper::Selector::parse("code").unwrap();
+
+        let mut answers = document
+            .select(&paragraphs)
+            .filter(|p| {
+                p.text()
+                    .collect::<String>()
+                    .starts_with("Your puzzle answer was")
+            })
+            .filter_map(|p| Some(p.select(&code).next()?.text().collect::<String>()));
+
+        KnownAnswers {
+            silver: answers.next(),
+            gold: answers.next(),
+        }
+    }
+
+    /// The accepted answer of a `level` (1 for silver, 2 for gold)
+    pub fn level(&self, level: u8) -> Option<&str> {
+        if level == 1 {
+            self.silver.as_deref()
+        } else {
+            self.gold.as_deref()
+        }
+    }
+}
+
+/// Accepted answers of every day of a year, stored in `src/data/<year>/answers.json`
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AnswerStore {
+    days: BTreeMap<u8, KnownAnswers>,
+}
+
+impl AnswerStore {
+    fn location(data_dir: &Path) -> PathBuf {
+        data_dir.join("answers.json")
+    }
+
+    pub fn load(data_dir: &Path) -> Result<AnswerStore, AdventError> {
+        store::load(&AnswerStore::location(data_dir))
+    }
+
+    pub fn save(&self, data_dir: &Path) -> Result<(), AdventError> {
+        store::save(&AnswerStore::location(data_dir), self)
+    }
+
+    pub fn get(&self, day: u8) -> Option<&KnownAnswers> {
+        self.days.get(&day)
+    }
+
+    /// Remember the accepted answer of a `level` (1 for silver, 2 for gold)
+    pub fn set(&mut self, day: u8, level: u8, answer: &str) {
+        let known = self.days.entry(day).or_default();
+        let slot = if level == 1 {
+            &mut known.silver
+        } else {
+            &mut known.gold
+        };
+        *slot = Some(answer.to_owned());
+    }
+
+    /// Remember every answer in `known`, keeping the ones it doesn't have
+    pub fn update(&mut self, day: u8, known: &KnownAnswers) {
+        for level in [1, 2] {
+            if let Some(answer) = known.level(level) {
+                self.set(day, level, answer);
+            }
+        }
+    }
+}
+
+/// How the answers of a run hold up against the known answers
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    /// Every known answer was given again
+    Passed,
+    /// Answers that differ from the accepted ones, as `(part, expected, actual)`
+    Mismatch(Vec<(&'static str, String, String)>),
+    /// The day could not be run, for instance because it panicked
+    Failed(String),
+    /// There is nothing to verify against, like a missing input or no accepted answers
+    Skipped(String),
+}
+
+impl Verdict {
+    pub fn check(known: &KnownAnswers, result: &RunResult) -> Verdict {
+        let parts = [
+            ("silver", &known.silver, result.silver.to_string()),
+            ("gold", &known.gold, result.gold.to_string()),
+        ];
+
+        let mismatches = parts
+            .into_iter()
+            .filter_map(|(part, expected, actual)| {
+                let expected = expected.as_ref()?;
+                (expected.trim() != actual.trim()).then(|| (part, expected.clone(), actual))
+            })
+            .collect::<Vec<_>>();
+
+        if mismatches.is_empty() {
+            Verdict::Passed
+        } else {
+            Verdict::Mismatch(mismatches)
+        }
+    }
+
+    /// Whether the verification as a whole should fail because of this day
+    pub fn is_failure(&self) -> bool {
+        matches!(self, Verdict::Mismatch(_) | Verdict::Failed(_))
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut Formatter<'_>) -> DisplayResult {
+        match self {
+            Verdict::Passed => write!(f, "ok"),
+            Verdict::Mismatch(mismatches) => {
+                let mismatches = mismatches
+                    .iter()
+                    .map(|(part, expected, actual)| {
+                        format!("{} was {} instead of {}", part, actual, expected)
+                    })
+                    .collect::<Vec<_>>();
+                write!(f, "mismatch: {}", mismatches.join(", "))
+            }
+            Verdict::Failed(reason) => write!(f, "failed: {}", reason),
+            Verdict::Skipped(reason) => write!(f, "skipped: {}", reason),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Output;
+
+    fn result(silver: u32, gold: u32) -> RunResult {
+        RunResult {
+            silver: Output::U32(silver),
+            gold: Output::U32(gold),
+            parse_time: 0,
+            silver_time: 0,
+            gold_time: 0,
+        }
+    }
+
+    #[test]
+    fn test_from_page() {
+        let page = "<main><article><p>The answer is <code>24000</code>.</p></article>\
+            <p>Your puzzle answer was <code>70000</code>.</p>\
+            <article><p>Part two</p></article>\
+            <p>Your puzzle answer was <code>EHPZPJGL</code>.</p></main>";
+        assert_eq!(
+            KnownAnswers::from_page(page),
+            KnownAnswers {
+                silver: Some(String::from("70000")),
+                gold: Some(String::from("EHPZPJGL")),
+            }
+        );
+        assert_eq!(
+            KnownAnswers::from_page("<main></main>"),
+            KnownAnswers::default()
+        );
+    }
+
+    #[test]
+    fn test_store() {
+        let mut store = AnswerStore::default();
+        store.set(1, 1, "24000");
+        store.set(1, 2, "45000");
+        store.set(2, 1, "15");
+
+        assert_eq!(
+            store.get(1),
+            Some(&KnownAnswers {
+                silver: Some(String::from("24000")),
+                gold: Some(String::from("45000")),
+            })
+        );
+        assert_eq!(store.get(2).unwrap().gold, None);
+        assert_eq!(store.get(3), None);
+
+        store.update(
+            2,
+            &KnownAnswers {
+                silver: None,
+                gold: Some(String::from("12")),
+            },
+        );
+        assert_eq!(store.get(2).unwrap().level(1), Some("15"));
+        assert_eq!(store.get(2).unwrap().level(2), Some("12"));
+    }
+
+    #[test]
+    fn test_verdict() {
+        let known = KnownAnswers {
+            silver: Some(String::from("24000")),
+            gold: None,
+        };
+
+        assert_eq!(Verdict::check(&known, &result(24000, 1)), Verdict::Passed);
+        let verdict = Verdict::check(&known, &result(23000, 1));
+        assert!(verdict.is_failure());
+        assert_eq!(
+            verdict.to_string(),
+            "mismatch: silver was 23000 instead of 24000"
+        );
+        assert!(!Verdict::Skipped(String::from("no input")).is_failure());
+    }
+}
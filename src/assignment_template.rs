@@ -1,4 +1,4 @@
-use crate::{Assignment, Output};
+use crate::{error::SolveError, Assignment, Output};
 
 pub struct Solution {}
 
@@ -11,39 +11,38 @@ impl Solution {
 impl Assignment for Solution {
     type Input = Vec<i32>;
     type Output = Output;
+    type Params = ();
 
-    fn parse_input(&self, input: &str) -> Option<Self::Input> {
-        None
+    fn parse_input(&self, _input: &str) -> Result<Self::Input, SolveError> {
+        Err(SolveError::new("The input is not parsed yet"))
     }
 
-    fn silver(&self, input: &Self::Input) -> Option<Self::Output> {
-        Some((-1).into())
+    fn silver(
+        &self,
+        _input: &Self::Input,
+        _params: &Self::Params,
+    ) -> Result<Self::Output, SolveError> {
+        Ok((-1).into())
     }
 
-    fn gold(&self, input: &Self::Input) -> Option<Self::Output> {
-        Some((-1).into())
+    fn gold(
+        &self,
+        _input: &Self::Input,
+        _params: &Self::Params,
+    ) -> Result<Self::Output, SolveError> {
+        Ok((-1).into())
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::testing::assignment_tests;
 
     static TEST_INPUT: &str = "";
 
-    #[test]
-    fn test_silver() {
-        let sol = Solution::new();
-        let input = sol.parse_input(TEST_INPUT);
-        let result = sol.silver(&input.unwrap()).unwrap();
-        assert_eq!(result, -1)
-    }
-
-    #[test]
-    fn test_gold() {
-        let sol = Solution::new();
-        let input = sol.parse_input(TEST_INPUT);
-        let result = sol.gold(&input.unwrap()).unwrap();
-        assert_eq!(result, -1)
+    assignment_tests! {
+        silver: TEST_INPUT => -1;
+        gold: TEST_INPUT => -1;
     }
 }
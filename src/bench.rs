@@ -0,0 +1,367 @@
+use std::{
+    fmt::{Display, Formatter, Result as DisplayResult},
+    path::Path,
+    time::{Duration, Instant},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{error::AdventError, registry, summary::format_time};
+
+/// How long and how often a day is solved while benchmarking
+#[derive(Debug, Clone, PartialEq)]
+pub struct BenchOptions {
+    /// Runs that are done first and thrown away, to warm up caches and the allocator
+    pub warmup: u32,
+    /// Stop after this many measured runs
+    pub iterations: Option<u32>,
+    /// Stop once the measured runs took this long together
+    pub budget: Option<Duration>,
+}
+
+/// Parse the seconds of `--budget`, which have to be a positive, finite number
+pub fn parse_budget(argument: &str) -> Result<Duration, String> {
+    match argument.parse::<f64>() {
+        Ok(seconds) if seconds > 0.0 => {
+            Duration::try_from_secs_f64(seconds).map_err(|err| err.to_string())
+        }
+        _ => Err(format!(
+            "Expected a positive number of seconds, found '{}'",
+            argument
+        )),
+    }
+}
+
+impl BenchOptions {
+    const DEFAULT_ITERATIONS: u32 = 10;
+
+    fn is_done(&self, runs: u32, elapsed: Duration) -> bool {
+        match (self.iterations, self.budget) {
+            (None, None) => runs >= Self::DEFAULT_ITERATIONS,
+            (iterations, budget) => {
+                iterations.is_some_and(|iterations| runs >= iterations)
+                    || budget.is_some_and(|budget| elapsed >= budget)
+            }
+        }
+    }
+}
+
+/// Summary of the nanoseconds a single phase took over all measured runs
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Stats {
+    pub min: u64,
+    pub median: u64,
+    pub mean: f64,
+    pub p95: u64,
+    pub stddev: f64,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[u64]) -> Option<Stats> {
+        if samples.is_empty() {
+            return None;
+        }
+
+        let mut sorted = samples.to_vec();
+        sorted.sort_unstable();
+        let count = sorted.len();
+
+        let median = if count.is_multiple_of(2) {
+            (sorted[count / 2 - 1] + sorted[count / 2]) / 2
+        } else {
+            sorted[count / 2]
+        };
+        let mean = sorted.iter().sum::<u64>() as f64 / count as f64;
+        let variance = sorted
+            .iter()
+            .map(|&sample| (sample as f64 - mean).powi(2))
+            .sum::<f64>()
+            / count as f64;
+        // Nearest rank, so the p95 is always one of the samples
+        let p95 = sorted[((count * 95).div_ceil(100)).max(1) - 1];
+
+        Some(Stats {
+            min: sorted[0],
+            median,
+            mean,
+            p95,
+            stddev: variance.sqrt(),
+        })
+    }
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut Formatter<'_>) -> DisplayResult {
+        write!(
+            f,
+            "min {} | median {} | mean {} | p95 {} | stddev {}",
+            format_time(self.min as u128),
+            format_time(self.median as u128),
+            format_time(self.mean as u128),
+            format_time(self.p95 as u128),
+            format_time(self.stddev as u128),
+        )
+    }
+}
+
+/// The result of benchmarking a day, as written by `bench --json`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BenchReport {
+    pub year: u16,
+    pub day: u8,
+    pub iterations: u32,
+    pub parse: Stats,
+    pub silver: Stats,
+    pub gold: Stats,
+}
+
+impl BenchReport {
+    pub fn phases(&self) -> [(&'static str, &Stats); 3] {
+        [
+            ("Parse", &self.parse),
+            ("Silver", &self.silver),
+            ("Gold", &self.gold),
+        ]
+    }
+
+    pub fn load(path: &Path) -> Result<BenchReport, AdventError> {
+        Ok(serde_json::from_str(&std::fs::read_to_string(path)?)?)
+    }
+}
+
+impl Display for BenchReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> DisplayResult {
+        writeln!(
+            f,
+            "Day {} of {}, {} iterations",
+            self.day, self.year, self.iterations
+        )?;
+        for (name, stats) in self.phases() {
+            writeln!(f, "| {:<6} | {}", name, stats)?;
+        }
+        Ok(())
+    }
+}
+
+/// How the median of a phase changed between two reports
+#[derive(Debug, Clone, PartialEq)]
+pub struct PhaseChange {
+    pub phase: String,
+    pub before: u64,
+    pub after: u64,
+}
+
+impl PhaseChange {
+    /// How much slower the phase got, in percent
+    pub fn change(&self) -> f64 {
+        percent_change(self.before, self.after)
+    }
+}
+
+impl Display for PhaseChange {
+    fn fmt(&self, f: &mut Formatter<'_>) -> DisplayResult {
+        write!(
+            f,
+            "| {:<6} | {} -> {} ({:+.1}%)",
+            self.phase,
+            format_time(self.before as u128),
+            format_time(self.after as u128),
+            self.change()
+        )
+    }
+}
+
+/// A phase of which the median got slower than the threshold allows
+#[derive(Debug, Clone, PartialEq)]
+pub struct Regression {
+    pub phase: String,
+    pub before: u64,
+    pub after: u64,
+}
+
+impl Regression {
+    /// How much slower the phase got, in percent
+    pub fn change(&self) -> f64 {
+        percent_change(self.before, self.after)
+    }
+}
+
+impl Display for Regression {
+    fn fmt(&self, f: &mut Formatter<'_>) -> DisplayResult {
+        write!(
+            f,
+            "{} regressed from {} to {} (+{:.1}%)",
+            self.phase,
+            format_time(self.before as u128),
+            format_time(self.after as u128),
+            self.change()
+        )
+    }
+}
+
+fn percent_change(before: u64, after: u64) -> f64 {
+    (after as f64 - before as f64) / (before.max(1) as f64) * 100.0
+}
+
+/// The change of the median of every phase
+pub fn changes(before: &BenchReport, after: &BenchReport) -> Vec<PhaseChange> {
+    before
+        .phases()
+        .into_iter()
+        .zip(after.phases())
+        .map(|((phase, old), (_, new))| PhaseChange {
+            phase: phase.to_owned(),
+            before: old.median,
+            after: new.median,
+        })
+        .collect()
+}
+
+/// The phases of which the median grew by more than `threshold` percent
+pub fn compare(before: &BenchReport, after: &BenchReport, threshold: f64) -> Vec<Regression> {
+    changes(before, after)
+        .into_iter()
+        .filter(|change| change.change() > threshold)
+        .map(|change| Regression {
+            phase: change.phase,
+            before: change.before,
+            after: change.after,
+        })
+        .collect()
+}
+
+/// Warm up, then solve a day until the options say to stop and summarize every phase
+pub fn bench(
+    year: u16,
+    day: u8,
+    input: String,
+    overrides: &[(String, String)],
+    options: &BenchOptions,
+) -> Result<BenchReport, AdventError> {
+    let registered = registry::find(year, day).ok_or(AdventError::NotSolved(year, day))?;
+
+    for _ in 0..options.warmup {
+        registered.solve_with(input.clone(), overrides)?;
+    }
+
+    let mut samples: [Vec<u64>; 3] = Default::default();
+    let mut runs = 0;
+    let before = Instant::now();
+    while runs == 0 || !options.is_done(runs, before.elapsed()) {
+        let result = registered.solve_with(input.clone(), overrides)?;
+        let times = [result.parse_time, result.silver_time, result.gold_time];
+        for (phase, time) in samples.iter_mut().zip(times) {
+            phase.push(time as u64);
+        }
+        runs += 1;
+    }
+
+    let [parse, silver, gold] = samples.map(|phase| Stats::from_samples(&phase).unwrap());
+    Ok(BenchReport {
+        year,
+        day,
+        iterations: runs,
+        parse,
+        silver,
+        gold,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stats(median: u64) -> Stats {
+        Stats {
+            min: median,
+            median,
+            mean: median as f64,
+            p95: median,
+            stddev: 0.0,
+        }
+    }
+
+    fn report(parse: u64, silver: u64, gold: u64) -> BenchReport {
+        BenchReport {
+            year: 2022,
+            day: 1,
+            iterations: 10,
+            parse: stats(parse),
+            silver: stats(silver),
+            gold: stats(gold),
+        }
+    }
+
+    #[test]
+    fn test_stats() {
+        let samples = (1..=20).rev().collect::<Vec<u64>>();
+        let stats = Stats::from_samples(&samples).unwrap();
+
+        assert_eq!(stats.min, 1);
+        assert_eq!(stats.median, 10);
+        assert_eq!(stats.mean, 10.5);
+        assert_eq!(stats.p95, 19);
+        assert!((stats.stddev - 5.766).abs() < 0.001);
+
+        assert_eq!(Stats::from_samples(&[7]).unwrap().p95, 7);
+        assert_eq!(Stats::from_samples(&[]), None);
+    }
+
+    #[test]
+    fn test_is_done() {
+        let default = BenchOptions {
+            warmup: 0,
+            iterations: None,
+            budget: None,
+        };
+        assert!(!default.is_done(9, Duration::from_secs(60)));
+        assert!(default.is_done(10, Duration::ZERO));
+
+        let budget = BenchOptions {
+            budget: Some(Duration::from_secs(1)),
+            ..default.clone()
+        };
+        assert!(!budget.is_done(1000, Duration::from_millis(999)));
+        assert!(budget.is_done(1, Duration::from_secs(1)));
+
+        let both = BenchOptions {
+            iterations: Some(5),
+            ..budget
+        };
+        assert!(both.is_done(5, Duration::ZERO));
+    }
+
+    #[test]
+    fn test_compare() {
+        let before = report(100, 1000, 2000);
+        let after = report(200, 1040, 1000);
+
+        let regressions = compare(&before, &after, 5.0);
+        assert_eq!(regressions.len(), 1);
+        assert_eq!(regressions[0].phase, "Parse");
+        assert_eq!(regressions[0].change(), 100.0);
+
+        assert_eq!(compare(&before, &after, 100.0), vec![]);
+
+        let changes = changes(&before, &after);
+        assert_eq!(changes.len(), 3);
+        assert_eq!(changes[2].change(), -50.0);
+    }
+
+    #[test]
+    fn test_parse_budget() {
+        assert_eq!(parse_budget("0.5"), Ok(Duration::from_millis(500)));
+        assert!(parse_budget("0").is_err());
+        assert!(parse_budget("-1").is_err());
+        assert!(parse_budget("NaN").is_err());
+        assert!(parse_budget("inf").is_err());
+    }
+
+    #[test]
+    fn test_report_json() {
+        let report = report(1, 2, 3);
+        let json = serde_json::to_string(&report).unwrap();
+
+        assert_eq!(serde_json::from_str::<BenchReport>(&json).unwrap(), report);
+    }
+}
@@ -0,0 +1,27 @@
+//! Serve a fake Advent of Code on localhost, for example:
+//!
+//! `cargo run --bin mock_aoc -- 8025` and then
+//! `ADVENT_BASE_URL=http://127.0.0.1:8025 cargo run -- fetch 1`
+use std::{net::TcpListener, sync::Arc};
+
+use advent_2022::mock::MockServer;
+
+static DAY_1: &str = "1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000\n";
+
+#[tokio::main]
+async fn main() -> std::io::Result<()> {
+    dotenv::dotenv().ok();
+
+    let port: u16 = std::env::args()
+        .nth(1)
+        .map(|port| port.parse().expect("The port should be a number"))
+        .unwrap_or(8025);
+    let session = dotenv::var("ADVENT_SESSION_KEY").unwrap_or_else(|_| String::from("mock"));
+
+    let listener = TcpListener::bind(("127.0.0.1", port))?;
+    println!("Mock Advent of Code listening on http://127.0.0.1:{}", port);
+
+    Arc::new(MockServer::new(&session).with_puzzle(1, DAY_1, "24000", "45000"))
+        .serve(listener)
+        .await
+}
@@ -0,0 +1,475 @@
+use std::{path::PathBuf, time::Duration};
+
+use clap::{Args, Parser, Subcommand, ValueEnum};
+
+use crate::{
+    bench::{self, BenchOptions, BenchReport},
+    client::AocClient,
+    description,
+    error::AdventError,
+    input::{self, Source},
+    params,
+    perf::{PerfEntry, PerfHistory},
+    report::{self, ReportFormat, Reporter},
+    RateLimitPolicy, SubmitPolicy,
+};
+
+#[derive(Debug, Parser)]
+#[command(about = "Solve, submit and benchmark Advent of Code assignments")]
+pub struct Cli {
+    /// The year of the puzzles, defaults to `ADVENT_YEAR` or 2022
+    #[arg(long, global = true)]
+    pub year: Option<u16>,
+    #[command(subcommand)]
+    pub command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Solve a day and choose which answer to submit
+    Run {
+        day: u8,
+        #[command(flatten)]
+        input: InputArgs,
+        #[command(flatten)]
+        params: ParamArgs,
+        /// Which answer to submit, defaults to `ADVENT_SUBMIT` or asking on stdin
+        #[arg(long, value_enum, value_name = "POLICY")]
+        submit: Option<SubmitPolicy>,
+        /// Only print the answers, same as `--submit never`
+        #[arg(long, conflicts_with = "submit")]
+        no_submit: bool,
+        #[command(flatten)]
+        rate_limit: RateLimitArgs,
+        #[command(flatten)]
+        report: ReportArgs,
+    },
+    /// Download and cache the puzzle input of a day
+    Fetch { day: u8 },
+    /// Extract the example of a day and its answers from the puzzle page
+    Example { day: u8 },
+    /// Generate the module of a new day from the template, with its example, and fetch its input
+    New { day: u8 },
+    /// Show the puzzle description of a day, from the archive when it was read before
+    Read {
+        day: u8,
+        /// Download the description again, for instance to see part two
+        #[arg(long)]
+        refresh: bool,
+    },
+    /// Solve a day and submit the answer of a single part
+    Submit {
+        day: u8,
+        part: Part,
+        #[command(flatten)]
+        input: InputArgs,
+        #[command(flatten)]
+        params: ParamArgs,
+        #[command(flatten)]
+        rate_limit: RateLimitArgs,
+    },
+    /// Submit the queued answers of which the rate limit has passed
+    Flush {
+        #[command(flatten)]
+        rate_limit: RateLimitArgs,
+    },
+    /// Run the unit tests of a day
+    Test { day: u8 },
+    /// Solve a day many times and report statistics of the timings
+    Bench {
+        day: u8,
+        #[command(flatten)]
+        input: InputArgs,
+        #[command(flatten)]
+        params: ParamArgs,
+        /// How many measured runs to do, defaults to 10 without `--budget`
+        #[arg(long)]
+        iterations: Option<u32>,
+        /// Keep measuring for this many seconds
+        #[arg(long, value_name = "SECONDS", value_parser = bench::parse_budget)]
+        budget: Option<Duration>,
+        /// How many runs to throw away before measuring
+        #[arg(long, default_value_t = 3)]
+        warmup: u32,
+        /// Print the report as JSON
+        #[arg(long)]
+        json: bool,
+        /// Compare against the JSON report of an earlier run
+        #[arg(long, value_name = "PATH")]
+        baseline: Option<PathBuf>,
+        /// Don't append the result to the performance history
+        #[arg(long)]
+        no_history: bool,
+        #[command(flatten)]
+        threshold: ThresholdArgs,
+        #[command(flatten)]
+        window: WindowArgs,
+    },
+    /// Look back at earlier benchmarks
+    Perf {
+        #[command(subcommand)]
+        command: PerfCommand,
+    },
+    /// Compare two JSON reports of `bench`
+    Compare {
+        before: PathBuf,
+        after: PathBuf,
+        #[command(flatten)]
+        threshold: ThresholdArgs,
+    },
+    /// Solve every day that has a solution and summarize them in one table
+    All {
+        #[command(flatten)]
+        report: ReportArgs,
+    },
+    /// Check that every day still gives the accepted answers on its cached input
+    Verify {
+        /// Only verify this day
+        day: Option<u8>,
+        #[command(flatten)]
+        report: ReportArgs,
+    },
+    /// Store the answers the puzzle pages show for days that were solved before, for `verify`
+    Seed {
+        /// Only seed this day
+        day: Option<u8>,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+pub enum PerfCommand {
+    /// Show how the timings of a day developed over the recorded benchmarks
+    History {
+        day: u8,
+        /// How many of the latest runs to show per part
+        #[arg(long, default_value_t = 20)]
+        limit: usize,
+        #[command(flatten)]
+        threshold: ThresholdArgs,
+        #[command(flatten)]
+        window: WindowArgs,
+    },
+}
+
+#[derive(Debug, Args)]
+pub struct InputArgs {
+    /// Read the input from this file, or from stdin with `-`, instead of the puzzle input.
+    /// Answers are not submitted then.
+    #[arg(long, value_name = "PATH")]
+    pub input: Option<PathBuf>,
+    /// Use an example from the puzzle description, counting from 1
+    #[arg(long, value_name = "N", conflicts_with = "input")]
+    pub example: Option<usize>,
+}
+
+impl InputArgs {
+    fn source(&self) -> Source {
+        Source::from_args(self.input.as_deref(), self.example)
+    }
+}
+
+#[derive(Debug, Args)]
+pub struct ParamArgs {
+    /// Override a parameter of the day, like `--param row=10`, answers are not submitted then
+    #[arg(long = "param", value_name = "NAME=VALUE", value_parser = params::parse_override)]
+    pub params: Vec<(String, String)>,
+}
+
+#[derive(Debug, Args)]
+pub struct ThresholdArgs {
+    /// How many percent slower a median may get before it counts as a regression
+    #[arg(long, value_name = "PERCENT", default_value_t = 5.0)]
+    pub threshold: f64,
+}
+
+#[derive(Debug, Args)]
+pub struct ReportArgs {
+    /// How to report the results
+    #[arg(long, value_enum, default_value_t = ReportFormat::Terminal)]
+    pub format: ReportFormat,
+    /// Write the report to this file instead of stdout
+    #[arg(long, value_name = "PATH")]
+    pub output: Option<PathBuf>,
+}
+
+impl ReportArgs {
+    fn reporter(&self) -> Result<Box<dyn Reporter>, AdventError> {
+        report::reporter(self.format, self.output.as_deref())
+    }
+}
+
+#[derive(Debug, Args)]
+pub struct WindowArgs {
+    /// How many earlier runs make up the rolling baseline
+    #[arg(long, value_name = "RUNS", default_value_t = 5)]
+    pub window: usize,
+}
+
+#[derive(Debug, Args)]
+pub struct RateLimitArgs {
+    /// What to do when an answer was given too recently
+    #[arg(long, value_enum, value_name = "POLICY", default_value_t = RateLimitPolicy::Report)]
+    pub on_rate_limit: RateLimitPolicy,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Part {
+    #[value(alias = "s", alias = "1")]
+    Silver,
+    #[value(alias = "g", alias = "2")]
+    Gold,
+}
+
+impl Part {
+    pub fn level(&self) -> u8 {
+        match self {
+            Part::Silver => 1,
+            Part::Gold => 2,
+        }
+    }
+}
+
+pub fn run(cli: Cli) -> Result<(), AdventError> {
+    let year = cli.year.unwrap_or_else(crate::year_from_env);
+
+    match cli.command {
+        Command::Run {
+            day,
+            input,
+            params,
+            submit,
+            no_submit,
+            rate_limit,
+            report,
+        } => {
+            let policy = if no_submit {
+                SubmitPolicy::Never
+            } else {
+                submit
+                    .or_else(SubmitPolicy::from_env)
+                    .unwrap_or(SubmitPolicy::Ask)
+            };
+            if policy != SubmitPolicy::Never {
+                flush(rate_limit.on_rate_limit);
+            }
+            let result = crate::solve(
+                year,
+                day,
+                &input.source(),
+                &params.params,
+                policy,
+                rate_limit.on_rate_limit,
+                report.reporter()?.as_mut(),
+            )?;
+            // The reporter owns stdout, which may be a JSON lines stream
+            if let Some(result) = result {
+                eprintln!("{}", result);
+            }
+            Ok(())
+        }
+        Command::Fetch { day } => {
+            let contents = crate::get_input(year, day)?;
+            println!(
+                "Input of day {} has {} lines",
+                day,
+                contents.lines().count()
+            );
+            Ok(())
+        }
+        Command::Example { day } => {
+            let example = AocClient::from_env()?.fetch_example(year, day)?;
+            let missing = String::from("not found");
+            println!(
+                "Example of day {} has {} lines, silver {}, gold {}",
+                day,
+                example.input.lines().count(),
+                example.silver.as_ref().unwrap_or(&missing),
+                example.gold.as_ref().unwrap_or(&missing)
+            );
+            Ok(())
+        }
+        Command::New { day } => {
+            let module = crate::new_day(year, day)?;
+            println!("Created {} for day {}", module.display(), day);
+            Ok(())
+        }
+        Command::Read { day, refresh } => {
+            let client = AocClient::from_env()?;
+            let markdown = if refresh {
+                client.fetch_description(year, day)?
+            } else {
+                client.description(year, day)?
+            };
+            if termion::is_tty(&std::io::stdout()) {
+                println!(
+                    "{}",
+                    description::render(&markdown, description::terminal_width())
+                );
+            } else {
+                print!("{}", markdown);
+            }
+            Ok(())
+        }
+        Command::Submit {
+            day,
+            part,
+            input,
+            params,
+            rate_limit,
+        } => {
+            flush(rate_limit.on_rate_limit);
+            let result = crate::submit(
+                year,
+                day,
+                part.level(),
+                &input.source(),
+                &params.params,
+                rate_limit.on_rate_limit,
+            )?;
+            println!("{}", result);
+            Ok(())
+        }
+        Command::Flush { rate_limit } => crate::flush_queue(rate_limit.on_rate_limit),
+        Command::Test { day } => {
+            let status = std::process::Command::new(env!("CARGO"))
+                .args([
+                    "test",
+                    "--lib",
+                    &format!("year_{}::assignment_{}::", year, day),
+                ])
+                .current_dir(env!("CARGO_MANIFEST_DIR"))
+                .status()?;
+            std::process::exit(status.code().unwrap_or(1));
+        }
+        Command::Bench {
+            day,
+            input,
+            params,
+            iterations,
+            budget,
+            warmup,
+            json,
+            baseline,
+            no_history,
+            threshold,
+            window,
+        } => {
+            let options = BenchOptions {
+                warmup,
+                iterations,
+                budget,
+            };
+            let raw_input = crate::read_input(year, day, &input.source())?;
+            let report = bench::bench(
+                year,
+                day,
+                raw_input.clone(),
+                &crate::input_overrides(year, day, &input.source(), &params.params)?,
+                &options,
+            )?;
+
+            if json {
+                println!("{}", serde_json::to_string_pretty(&report)?);
+            } else {
+                print!("{}", report);
+            }
+
+            // Timings with other parameters would skew the baseline of the real input
+            if !no_history && params.params.is_empty() {
+                record(&report, &raw_input, threshold.threshold, window.window)?;
+            }
+
+            match baseline {
+                Some(baseline) => compare(&BenchReport::load(&baseline)?, &report, threshold, json),
+                None => Ok(()),
+            }
+        }
+        Command::Perf {
+            command:
+                PerfCommand::History {
+                    day,
+                    limit,
+                    threshold,
+                    window,
+                },
+        } => {
+            PerfHistory::load(&crate::data_dir()?)?.print_trend(
+                year,
+                day,
+                limit,
+                window.window,
+                threshold.threshold,
+            );
+            Ok(())
+        }
+        Command::Compare {
+            before,
+            after,
+            threshold,
+        } => compare(
+            &BenchReport::load(&before)?,
+            &BenchReport::load(&after)?,
+            threshold,
+            false,
+        ),
+        Command::All { report } => crate::solve_all(year, report.reporter()?.as_mut()),
+        Command::Verify { day, report } => crate::verify(year, day, report.reporter()?.as_mut()),
+        Command::Seed { day } => crate::seed_answers(year, day),
+    }
+}
+
+/// Append a benchmark to the performance history and warn about parts that got slower than their rolling baseline
+fn record(
+    report: &BenchReport,
+    raw_input: &str,
+    threshold: f64,
+    window: usize,
+) -> Result<(), AdventError> {
+    let data_dir = crate::data_dir()?;
+    let history = PerfHistory::load(&data_dir)?;
+    let entries = PerfEntry::from_report(report, &input::hash(raw_input));
+
+    for entry in &entries {
+        if let Some(regression) = history.check(entry, window, threshold) {
+            eprintln!(
+                "Warning: {} compared to the last {} runs",
+                regression, window
+            );
+        }
+    }
+
+    PerfHistory::append(&data_dir, &entries)
+}
+
+/// Show the change of every phase and fail when one regressed. With `json` the rows go to
+/// stderr, so stdout stays a single JSON report.
+fn compare(
+    before: &BenchReport,
+    after: &BenchReport,
+    threshold: ThresholdArgs,
+    json: bool,
+) -> Result<(), AdventError> {
+    for change in bench::changes(before, after) {
+        if json {
+            eprintln!("{}", change);
+        } else {
+            println!("{}", change);
+        }
+    }
+
+    let regressions = bench::compare(before, after, threshold.threshold);
+    for regression in &regressions {
+        eprintln!("{}", regression);
+    }
+
+    match regressions.len() {
+        0 => Ok(()),
+        count => Err(AdventError::Regressed(count)),
+    }
+}
+
+fn flush(rate_limit: RateLimitPolicy) {
+    if let Err(err) = crate::flush_queue(rate_limit) {
+        eprintln!("Could not flush the submission queue: {}", err);
+    }
+}
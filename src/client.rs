@@ -0,0 +1,330 @@
+use std::{
+    fmt::Display,
+    path::{Path, PathBuf},
+};
+
+use reqwest::{
+    header::{HeaderMap, HeaderValue, CONTENT_TYPE, COOKIE, USER_AGENT},
+    redirect::Policy,
+    Client,
+};
+
+use crate::{
+    answers::{AnswerStore, KnownAnswers},
+    description,
+    error::AdventError,
+    examples::{self, Example, ExampleStore},
+    history::SubmissionHistory,
+    input, submission,
+    submission::SubmissionResult,
+};
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+/// Before years were tracked everything of 2022 was kept directly in the data directory
+const LEGACY_YEAR: u16 = 2022;
+const USER_AGENT_STRING: &str = "github.com/rboortman/advent-2022 by ron@techforce1.nl";
+
+/// Talks to Advent of Code, or anything that behaves like it, and keeps the
+/// inputs and submission history of every year in `data_dir/<year>`
+#[derive(Debug, Clone)]
+pub struct AocClient {
+    base_url: String,
+    session: Option<String>,
+    data_dir: PathBuf,
+}
+
+impl AocClient {
+    pub fn new(base_url: &str, session: Option<String>, data_dir: PathBuf) -> AocClient {
+        AocClient {
+            base_url: base_url.trim_end_matches('/').to_owned(),
+            session,
+            data_dir,
+        }
+    }
+
+    /// Configure the client from `ADVENT_BASE_URL` and `ADVENT_SESSION_KEY`
+    pub fn from_env() -> Result<AocClient, AdventError> {
+        let base_url =
+            dotenv::var("ADVENT_BASE_URL").unwrap_or_else(|_| String::from(DEFAULT_BASE_URL));
+
+        Ok(AocClient::new(
+            &base_url,
+            crate::session_key().ok(),
+            crate::data_dir()?,
+        ))
+    }
+
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    pub fn data_dir(&self) -> &Path {
+        &self.data_dir
+    }
+
+    /// The directory with the inputs and submission history of a year
+    pub fn year_dir(&self, year: u16) -> Result<PathBuf, AdventError> {
+        let dir = self.data_dir.join(year.to_string());
+        std::fs::create_dir_all(&dir)?;
+
+        if year == LEGACY_YEAR {
+            migrate_legacy(&self.data_dir, &dir)?;
+        }
+
+        Ok(dir)
+    }
+
+    /// Where the data of a year is found without touching the disk: unlike [`AocClient::year_dir`]
+    /// nothing is created, and files of the old flat layout are read where they are
+    pub fn existing_year_dir(&self, year: u16) -> PathBuf {
+        let dir = self.data_dir.join(year.to_string());
+        if year == LEGACY_YEAR && !dir.exists() {
+            return self.data_dir.clone();
+        }
+        dir
+    }
+
+    fn build_client(&self, content_type: &'static str) -> Result<Client, AdventError> {
+        build_client(
+            self.session.as_deref().ok_or(AdventError::MissingSession)?,
+            content_type,
+        )
+    }
+
+    fn input_location(&self, year: u16, day: u8) -> Result<PathBuf, AdventError> {
+        Ok(input_location(&self.year_dir(year)?, day))
+    }
+
+    /// The puzzle input of a day when it was downloaded before and is still intact
+    pub fn cached_input(&self, year: u16, day: u8) -> Option<String> {
+        input::read_cached(&input_location(&self.existing_year_dir(year), day))
+    }
+
+    /// Read the puzzle input of a day from the cache, downloading it when it is missing or corrupted
+    #[tokio::main]
+    pub async fn get_input(&self, year: u16, day: u8) -> Result<String, AdventError> {
+        let data_location = self.input_location(year, day)?;
+
+        if let Some(contents) = input::read_cached(&data_location) {
+            if let Err(err) = input::store_missing_hash(&data_location, &contents) {
+                eprintln!(
+                    "Could not store the hash of {}: {}",
+                    data_location.display(),
+                    err
+                );
+            }
+            return Ok(contents);
+        }
+
+        let response = self
+            .build_client("text/plain")?
+            .get(format!("{}/{}/day/{}/input", self.base_url, year, day))
+            .send()
+            .await?;
+        let status = response.status().as_u16();
+        let content_type = response
+            .headers()
+            .get(CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .map(String::from);
+        let contents = response.text().await?;
+
+        input::check(day, status, content_type.as_deref(), &contents)?;
+        input::write_cached(&data_location, &contents)?;
+
+        Ok(contents)
+    }
+
+    /// Download the puzzle page of a day, which shows part two once part one is solved
+    #[tokio::main]
+    pub async fn get_puzzle_page(&self, year: u16, day: u8) -> Result<String, AdventError> {
+        let response = self
+            .build_client("text/html")?
+            .get(format!("{}/{}/day/{}", self.base_url, year, day))
+            .send()
+            .await?;
+        if !response.status().is_success() {
+            return Err(AdventError::UnexpectedStatus(response.status().as_u16()));
+        }
+
+        Ok(response.text().await?)
+    }
+
+    /// Count how many parts of a day have been solved according to the puzzle page
+    pub fn solved_levels(&self, year: u16, day: u8) -> Result<usize, AdventError> {
+        let contents = self.get_puzzle_page(year, day)?;
+
+        Ok(contents.matches("Your puzzle answer was").count())
+    }
+
+    /// Keep the answers the puzzle page shows for the solved parts of a day, so days that were
+    /// solved before answers were stored can be verified too
+    pub fn seed_answers(&self, year: u16, day: u8) -> Result<KnownAnswers, AdventError> {
+        let known = KnownAnswers::from_page(&self.get_puzzle_page(year, day)?);
+        self.store_answers(year, day, &known)?;
+
+        Ok(known)
+    }
+
+    fn store_answers(&self, year: u16, day: u8, known: &KnownAnswers) -> Result<(), AdventError> {
+        let year_dir = self.year_dir(year)?;
+        let mut answers = AnswerStore::load(&year_dir)?;
+        answers.update(day, known);
+        answers.save(&year_dir)
+    }
+
+    /// Extract the example of a day from its puzzle page and store it with the examples of the year
+    pub fn fetch_example(&self, year: u16, day: u8) -> Result<Example, AdventError> {
+        self.store_example(year, day, &self.get_puzzle_page(year, day)?)
+    }
+
+    /// Extract the example of a day from a puzzle page that was already downloaded and store it
+    pub fn store_example(&self, year: u16, day: u8, page: &str) -> Result<Example, AdventError> {
+        let example = examples::extract(page).ok_or(AdventError::NoExample(day, 1))?;
+
+        let year_dir = self.year_dir(year)?;
+        let mut store = ExampleStore::load(&year_dir, day)?;
+        store.update(example.clone());
+        store.save(&year_dir, day)?;
+
+        Ok(example)
+    }
+
+    /// Convert the puzzle page of a day to Markdown and keep it next to the input,
+    /// replacing the copy from before part two was unlocked
+    pub fn fetch_description(&self, year: u16, day: u8) -> Result<String, AdventError> {
+        let page = self.get_puzzle_page(year, day)?;
+        let markdown = description::to_markdown(&page).ok_or(AdventError::MissingArticle)?;
+        description::write_cached(&self.year_dir(year)?, day, &markdown)?;
+
+        Ok(markdown)
+    }
+
+    /// The description of a day as Markdown, downloaded only when it is not cached yet
+    pub fn description(&self, year: u16, day: u8) -> Result<String, AdventError> {
+        match description::read_cached(&self.year_dir(year)?, day) {
+            Some(markdown) => Ok(markdown),
+            None => self.fetch_description(year, day),
+        }
+    }
+
+    /// Submit an answer, unless the submission history already knows it can't be right.
+    /// A correct answer is kept in the answer store so `verify` can check it later.
+    pub fn send_answer(
+        &self,
+        year: u16,
+        day: u8,
+        level: u8,
+        answer: impl Display,
+    ) -> Result<SubmissionResult, AdventError> {
+        let answer = answer.to_string();
+        let year_dir = self.year_dir(year)?;
+        let mut history = SubmissionHistory::load(&year_dir)?;
+        history.check(day, level, &answer)?;
+
+        let mut result = self.post_answer(year, day, level, &answer)?;
+        // Advent of Code answers gold that is already solved just like gold that is still
+        // locked, only the puzzle page tells them apart. It also shows the accepted answers,
+        // which the answer store may not know when they were solved elsewhere.
+        if matches!(
+            result,
+            SubmissionResult::WrongLevel | SubmissionResult::AlreadySolved
+        ) {
+            match self.seed_answers(year, day) {
+                Ok(known) if known.level(level).is_some() => {
+                    result = SubmissionResult::AlreadySolved
+                }
+                Ok(_) => (),
+                Err(err) => eprintln!("Could not read the answers of day {}: {}", day, err),
+            }
+        }
+        history.record(day, level, &answer, result.clone());
+        history.save(&year_dir)?;
+
+        if result == SubmissionResult::Correct {
+            let mut answers = AnswerStore::load(&year_dir)?;
+            answers.set(day, level, &answer);
+            answers.save(&year_dir)?;
+
+            // Solving part one unlocks part two, which the archive should not miss
+            if level == 1 {
+                if let Err(err) = self.fetch_description(year, day) {
+                    eprintln!("Could not update the description of day {}: {}", day, err);
+                }
+            }
+        }
+
+        Ok(result)
+    }
+
+    #[tokio::main]
+    async fn post_answer(
+        &self,
+        year: u16,
+        day: u8,
+        level: u8,
+        answer: &str,
+    ) -> Result<SubmissionResult, AdventError> {
+        let response = self
+            .build_client("application/x-www-form-urlencoded")?
+            .post(format!("{}/{}/day/{}/answer", self.base_url, year, day))
+            .form(&[("level", level.to_string().as_str()), ("answer", answer)])
+            .send()
+            .await?;
+
+        // Advent of Code redirects answers of sessions it doesn't accept
+        if response.status().is_redirection() {
+            return Err(AdventError::NotLoggedIn);
+        }
+        if !response.status().is_success() {
+            return Err(AdventError::UnexpectedStatus(response.status().as_u16()));
+        }
+
+        let contents = response.text().await?;
+
+        let message = submission::article_text(&contents).ok_or(AdventError::MissingArticle)?;
+
+        SubmissionResult::from_message(&message, level).ok_or(AdventError::UnknownResponse(message))
+    }
+}
+
+pub fn build_client(
+    session_cookie: &str,
+    content_type: &'static str,
+) -> Result<Client, AdventError> {
+    let cookie_header = HeaderValue::from_str(&format!("session={}", session_cookie.trim()))
+        .map_err(|err| AdventError::InvalidCookie(err.to_string()))?;
+
+    let mut headers = HeaderMap::new();
+    headers.insert(COOKIE, cookie_header);
+    headers.insert(CONTENT_TYPE, HeaderValue::from_static(content_type));
+    headers.insert(USER_AGENT, HeaderValue::from_static(USER_AGENT_STRING));
+
+    Ok(Client::builder()
+        .default_headers(headers)
+        .redirect(Policy::none())
+        .build()?)
+}
+
+fn input_location(year_dir: &Path, day: u8) -> PathBuf {
+    year_dir.join(format!("input_{}.txt", day))
+}
+
+/// Move the inputs and submission history of the old flat layout into the directory of its year
+fn migrate_legacy(data_dir: &Path, year_dir: &Path) -> Result<(), AdventError> {
+    for entry in std::fs::read_dir(data_dir)? {
+        let path = entry?.path();
+        let name = match path.file_name().and_then(|name| name.to_str()) {
+            Some(name) => name.to_owned(),
+            None => continue,
+        };
+
+        let is_legacy = name.starts_with("input_") || name == "submissions.json";
+        let target = year_dir.join(&name);
+        if path.is_file() && is_legacy && !target.exists() {
+            std::fs::rename(&path, target)?;
+        }
+    }
+
+    Ok(())
+}
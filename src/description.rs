@@ -0,0 +1,368 @@
+//! Puzzle descriptions converted to Markdown, so they can be read offline in the terminal.
+
+use std::path::{Path, PathBuf};
+
+use ego_tree::NodeRef;
+use scraper::{ElementRef, Node};
+use termion::{color, style};
+
+use crate::error::AdventError;
+
+/// Paragraphs are wrapped at this width when the terminal is wider, or unknown
+const MAX_WIDTH: usize = 100;
+
+/// Convert the `<article class="day-desc">` blocks of a puzzle page to Markdown,
+/// one for each part that is unlocked
+pub fn to_markdown(html: &str) -> Option<String> {
+    let document = scraper::Html::parse_document(html);
+    let selector = scraper::Selector::parse("article.day-desc").unwrap();
+
+    let articles = document
+        .select(&selector)
+        .map(|article| blocks(*article).join("\n\n"))
+        .collect::<Vec<String>>();
+
+    if articles.is_empty() {
+        None
+    } else {
+        Some(articles.join("\n\n") + "\n")
+    }
+}
+
+fn blocks(node: NodeRef<Node>) -> Vec<String> {
+    let mut blocks = Vec::new();
+    for child in node.children() {
+        let element = match ElementRef::wrap(child) {
+            Some(element) => element,
+            None => {
+                let text = inline(child);
+                if !text.trim().is_empty() {
+                    blocks.push(text.trim().to_owned());
+                }
+                continue;
+            }
+        };
+
+        match element.value().name() {
+            "h2" => blocks.push(format!("## {}", element.text().collect::<String>())),
+            "pre" => blocks.push(format!(
+                "```\n{}\n```",
+                element.text().collect::<String>().trim_end()
+            )),
+            "ul" | "ol" => blocks.push(
+                element
+                    .children()
+                    .filter(|item| {
+                        ElementRef::wrap(*item).is_some_and(|item| item.value().name() == "li")
+                    })
+                    .map(|item| format!("- {}", inline(item).trim()))
+                    .collect::<Vec<String>>()
+                    .join("\n"),
+            ),
+            "p" => blocks.push(inline(child).trim().to_owned()),
+            _ => blocks.extend(blocks_or_inline(child)),
+        }
+    }
+    blocks
+}
+
+/// Elements like `<div>` can hold either blocks or only text
+fn blocks_or_inline(node: NodeRef<Node>) -> Vec<String> {
+    let has_blocks = node.children().any(|child| {
+        ElementRef::wrap(child)
+            .is_some_and(|child| matches!(child.value().name(), "p" | "pre" | "ul" | "ol" | "h2"))
+    });
+    if has_blocks {
+        blocks(node)
+    } else {
+        vec![inline(node).trim().to_owned()]
+    }
+}
+
+fn inline(node: NodeRef<Node>) -> String {
+    let children = || node.children().map(inline).collect::<String>();
+
+    match node.value() {
+        Node::Text(text) => escape(&text.replace('\n', " ")),
+        Node::Element(element) => match element.name() {
+            "code" => {
+                let code = ElementRef::wrap(node)
+                    .map(|code| code.text().collect::<String>())
+                    .unwrap_or_default();
+                let emphasized = node.descendants().skip(1).any(|descendant| {
+                    ElementRef::wrap(descendant).is_some_and(|d| d.value().name() == "em")
+                });
+                if emphasized {
+                    format!("*`{}`*", code)
+                } else {
+                    format!("`{}`", code)
+                }
+            }
+            "em" => match node.first_child().and_then(ElementRef::wrap) {
+                Some(child) if child.value().name() == "code" => children(),
+                _ => format!("*{}*", children()),
+            },
+            "a" => match element.attr("href") {
+                Some(href) => format!("[{}]({})", children(), href),
+                None => children(),
+            },
+            _ => children(),
+        },
+        _ => String::new(),
+    }
+}
+
+fn escape(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace('*', "\\*")
+        .replace('`', "\\`")
+}
+
+/// Where the description of a day is cached, next to its input
+pub fn location(year_dir: &Path, day: u8) -> PathBuf {
+    year_dir.join(format!("description_{}.md", day))
+}
+
+pub fn read_cached(year_dir: &Path, day: u8) -> Option<String> {
+    std::fs::read_to_string(location(year_dir, day)).ok()
+}
+
+pub fn write_cached(year_dir: &Path, day: u8, markdown: &str) -> Result<(), AdventError> {
+    Ok(std::fs::write(location(year_dir, day), markdown)?)
+}
+
+/// Style the Markdown of [`to_markdown`] for a terminal: bold headings and emphasis,
+/// colored code, and paragraphs wrapped to `width`
+pub fn render(markdown: &str, width: usize) -> String {
+    let mut lines = Vec::new();
+    let mut in_block = false;
+
+    for line in markdown.lines() {
+        if line == "```" {
+            in_block = !in_block;
+            continue;
+        }
+
+        if in_block {
+            lines.push(format!(
+                "    {}{}{}",
+                color::Fg(color::Green),
+                line,
+                color::Fg(color::Reset)
+            ));
+        } else if let Some(heading) = line.strip_prefix("## ") {
+            lines.push(format!(
+                "{}{}{}",
+                style::Bold,
+                unescape(heading),
+                style::Reset
+            ));
+        } else if let Some(item) = line.strip_prefix("- ") {
+            let mut open = Open::default();
+            let wrapped = wrap(item, width.saturating_sub(2));
+            for (i, part) in wrapped.iter().enumerate() {
+                let bullet = if i == 0 { "- " } else { "  " };
+                lines.push(format!("{}{}", bullet, style_inline(part, &mut open)));
+            }
+        } else {
+            let mut open = Open::default();
+            lines.extend(
+                wrap(line, width)
+                    .iter()
+                    .map(|part| style_inline(part, &mut open)),
+            );
+        }
+    }
+
+    lines.join("\n")
+}
+
+/// The width to render with: the terminal width, but never more than [`MAX_WIDTH`]
+pub fn terminal_width() -> usize {
+    termion::terminal_size()
+        .map(|(width, _)| (width as usize).min(MAX_WIDTH))
+        .unwrap_or(MAX_WIDTH)
+}
+
+/// Break a line between words so the visible text fits in `width`
+fn wrap(line: &str, width: usize) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut current = String::new();
+
+    for word in line.split(' ') {
+        if !current.is_empty() && visible_len(&current) + 1 + visible_len(word) > width {
+            lines.push(std::mem::take(&mut current));
+        }
+        if !current.is_empty() {
+            current.push(' ');
+        }
+        current.push_str(word);
+    }
+    lines.push(current);
+    lines
+}
+
+/// The number of characters that remain once the markers of [`style_inline`] are gone
+fn visible_len(text: &str) -> usize {
+    let mut len = 0;
+    let mut chars = text.chars();
+    let mut code = false;
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' if !code => len += chars.next().map_or(0, |_| 1),
+            '`' => code = !code,
+            '*' if !code => (),
+            _ => len += 1,
+        }
+    }
+    len
+}
+
+fn unescape(text: &str) -> String {
+    let mut result = String::new();
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => result.extend(chars.next()),
+            c => result.push(c),
+        }
+    }
+    result
+}
+
+/// The spans that are still open at the end of a wrapped line, and continue on the next one
+#[derive(Debug, Default, Clone, Copy)]
+struct Open {
+    emphasis: bool,
+    code: bool,
+}
+
+fn emphasis_style() -> String {
+    format!("{}{}", style::Bold, color::Fg(color::LightYellow))
+}
+
+/// Turn `*emphasis*` and `` `code` `` into terminal styles. Spans that `open` carries over from
+/// the previous line are styled again, and spans that remain open are reset at the end.
+fn style_inline(text: &str, open: &mut Open) -> String {
+    let mut result = String::new();
+    let mut chars = text.chars();
+
+    if open.emphasis {
+        result.push_str(&emphasis_style());
+    }
+    if open.code {
+        result.push_str(&color::Fg(color::Green).to_string());
+    }
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' if !open.code => result.extend(chars.next()),
+            '`' => {
+                open.code = !open.code;
+                if open.code {
+                    result.push_str(&color::Fg(color::Green).to_string());
+                } else {
+                    result.push_str(&color::Fg(color::Reset).to_string());
+                }
+            }
+            '*' if !open.code => {
+                open.emphasis = !open.emphasis;
+                if open.emphasis {
+                    result.push_str(&emphasis_style());
+                } else {
+                    result.push_str(style::Reset.as_ref());
+                }
+            }
+            c => result.push(c),
+        }
+    }
+
+    if open.emphasis || open.code {
+        result.push_str(style::Reset.as_ref());
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static PAGE: &str = "<main><article class=\"day-desc\"><h2>--- Day 1: Calorie Counting ---</h2>\
+        <p>The Elves take turns writing down the number of <em>Calories</em> (see <a href=\"/2022/about\">about</a>).</p>\
+        <pre><code>1000\n2000\n</code></pre>\
+        <ul><li>The first Elf carries <code>3000</code>.</li><li>Prices go up 2*3.</li></ul>\
+        <p>The answer is <code><em>24000</em></code>.</p></article>\
+        <p>Your puzzle answer was <code>70000</code>.</p></main>";
+
+    #[test]
+    fn test_to_markdown() {
+        assert_eq!(
+            to_markdown(PAGE).unwrap(),
+            [
+                "## --- Day 1: Calorie Counting ---",
+                "",
+                "The Elves take turns writing down the number of *Calories* (see [about](/2022/about)).",
+                "",
+                "```\n1000\n2000\n```",
+                "",
+                "- The first Elf carries `3000`.\n- Prices go up 2\\*3.",
+                "",
+                "The answer is *`24000`*.\n",
+            ]
+            .join("\n")
+        );
+        assert_eq!(to_markdown("<main></main>"), None);
+    }
+
+    #[test]
+    fn test_render() {
+        let rendered = render("## Title\n\nSome *very* long text with `a*b` and 2\\*3", 16);
+        let lines = rendered.lines().collect::<Vec<&str>>();
+
+        assert_eq!(lines[0], format!("{}Title{}", style::Bold, style::Reset));
+        assert_eq!(lines[1], "");
+        assert_eq!(
+            lines[2],
+            format!(
+                "Some {}{}very{} long",
+                style::Bold,
+                color::Fg(color::LightYellow),
+                style::Reset
+            )
+        );
+        assert_eq!(
+            lines[3],
+            format!(
+                "text with {}a*b{}",
+                color::Fg(color::Green),
+                color::Fg(color::Reset)
+            )
+        );
+        assert_eq!(lines[4], "and 2*3");
+    }
+
+    #[test]
+    fn test_render_wrapped_spans() {
+        let rendered = render("Some *very long words* here\n- `one two`", 12);
+        let lines = rendered.lines().collect::<Vec<&str>>();
+        let emphasis = format!("{}{}", style::Bold, color::Fg(color::LightYellow));
+        let code = color::Fg(color::Green).to_string();
+
+        assert_eq!(lines[0], format!("Some {}very{}", emphasis, style::Reset));
+        assert_eq!(lines[1], format!("{}long words{}", emphasis, style::Reset));
+        assert_eq!(lines[2], "here");
+        assert_eq!(
+            lines[3],
+            format!("- {}one two{}", code, color::Fg(color::Reset))
+        );
+
+        let narrow = render("- `one two`", 6);
+        let lines = narrow.lines().collect::<Vec<&str>>();
+        assert_eq!(lines[0], format!("- {}one{}", code, style::Reset));
+        assert_eq!(
+            lines[1],
+            format!("  {}two{}", code, color::Fg(color::Reset))
+        );
+    }
+}
@@ -0,0 +1,293 @@
+use std::{
+    fmt::{Display, Formatter, Result as DisplayResult},
+    io,
+};
+
+#[derive(Debug)]
+pub enum AdventError {
+    /// `ADVENT_SESSION_KEY` is not set
+    MissingSession,
+    /// The session key can't be used as a cookie header
+    InvalidCookie(String),
+    /// The session cookie was rejected or has expired
+    NotLoggedIn,
+    /// The puzzle of this day has not been unlocked yet
+    NotUnlocked(u8),
+    /// There is no solution for this year and day
+    NotSolved(u16, u8),
+    /// The day has no example with this number
+    NoExample(u8, usize),
+    /// A module for this year and day exists already
+    DayExists(u16, u8),
+    /// The year has no `src/year_<year>/mod.rs` to register days in
+    MissingYear(u16),
+    Network(reqwest::Error),
+    /// Advent of Code answered with a status that was not expected
+    UnexpectedStatus(u16),
+    /// A page that should explain the outcome of a request has no `<article>`
+    MissingArticle,
+    /// A response `<article>` with a message that is not understood
+    UnknownResponse(String),
+    /// A response or cached file does not look like a puzzle input
+    InvalidInput(String),
+    /// The submission history refuses to send an answer
+    Refused(String),
+    /// A local state file in the data directory could not be read
+    InvalidState(serde_json::Error),
+    Io(io::Error),
+    /// Phases of a benchmark got slower than the threshold allows
+    Regressed(usize),
+    /// Days of which the answers no longer match the accepted answers
+    VerifyFailed(usize),
+    /// An assignment could not parse or solve the named input
+    Solve(String, Box<SolveError>),
+}
+
+impl Display for AdventError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> DisplayResult {
+        match self {
+            AdventError::MissingSession => write!(f, "ADVENT_SESSION_KEY is not set"),
+            AdventError::InvalidCookie(err) => write!(f, "Invalid session cookie: {}", err),
+            AdventError::NotLoggedIn => write!(
+                f,
+                "Not logged in, check that ADVENT_SESSION_KEY holds a valid session"
+            ),
+            AdventError::NotUnlocked(day) => write!(f, "Day {} has not been unlocked yet", day),
+            AdventError::NotSolved(year, day) => {
+                write!(f, "Day {} of {} has not been solved yet", day, year)
+            }
+            AdventError::NoExample(day, number) => {
+                write!(f, "Day {} has no example {}", day, number)
+            }
+            AdventError::DayExists(year, day) => {
+                write!(f, "Day {} of {} has a module already", day, year)
+            }
+            AdventError::MissingYear(year) => {
+                write!(
+                    f,
+                    "There is no src/year_{}/mod.rs to register days in",
+                    year
+                )
+            }
+            AdventError::Network(err) => write!(f, "Network error: {}", err),
+            AdventError::UnexpectedStatus(status) => {
+                write!(f, "Advent of Code answered with status {}", status)
+            }
+            AdventError::MissingArticle => write!(f, "The response has no <article>"),
+            AdventError::UnknownResponse(message) => {
+                write!(f, "Unknown response from Advent of Code: {}", message)
+            }
+            AdventError::InvalidInput(reason) => write!(f, "Invalid puzzle input: {}", reason),
+            AdventError::Refused(reason) => write!(f, "Not submitted: {}", reason),
+            AdventError::InvalidState(err) => write!(f, "Invalid local state: {}", err),
+            AdventError::Io(err) => write!(f, "I/O error: {}", err),
+            AdventError::Regressed(count) => {
+                write!(f, "{} phase(s) regressed beyond the threshold", count)
+            }
+            AdventError::VerifyFailed(count) => {
+                write!(f, "{} day(s) did not give the accepted answers", count)
+            }
+            AdventError::Solve(input, err) => write!(f, "{}", err.snippet(input)),
+        }
+    }
+}
+
+impl std::error::Error for AdventError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            AdventError::Network(err) => Some(err),
+            AdventError::InvalidState(err) => Some(err),
+            AdventError::Io(err) => Some(err),
+            AdventError::Solve(_, err) => Some(err.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for AdventError {
+    fn from(err: io::Error) -> Self {
+        AdventError::Io(err)
+    }
+}
+
+impl From<reqwest::Error> for AdventError {
+    fn from(err: reqwest::Error) -> Self {
+        AdventError::Network(err)
+    }
+}
+
+impl From<serde_json::Error> for AdventError {
+    fn from(err: serde_json::Error) -> Self {
+        AdventError::InvalidState(err)
+    }
+}
+
+impl From<SolveError> for AdventError {
+    fn from(err: SolveError) -> Self {
+        AdventError::Solve(String::from("input"), Box::new(err))
+    }
+}
+
+/// Where in the input an assignment ran into something it did not understand
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Location {
+    /// Line number, starting at 1
+    pub line: usize,
+    /// Column of the offending text in characters, starting at 1
+    pub column: usize,
+    /// The text that could not be understood
+    pub text: String,
+    /// The whole line the text was found on
+    pub source: String,
+}
+
+/// Why an assignment could not parse its input or solve a part
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SolveError {
+    pub message: String,
+    pub location: Option<Location>,
+    /// A suggestion of what the input should have looked like
+    pub hint: Option<String>,
+}
+
+impl SolveError {
+    pub fn new(message: impl Into<String>) -> SolveError {
+        SolveError {
+            message: message.into(),
+            location: None,
+            hint: None,
+        }
+    }
+
+    pub fn with_hint(mut self, hint: impl Into<String>) -> SolveError {
+        self.hint = Some(hint.into());
+        self
+    }
+
+    /// Mention the part that was being solved in the message
+    pub fn in_part(mut self, part: &str) -> SolveError {
+        self.message = format!("Could not solve {}: {}", part, self.message);
+        self
+    }
+
+    /// An error about `text`, found on line `line` (starting at 0) of which the contents are `source`.
+    /// When `text` is a slice of `source` its column is exact, otherwise it is searched for.
+    pub fn at(line: usize, source: &str, text: &str, message: impl Into<String>) -> SolveError {
+        let start = source.as_ptr() as usize;
+        let offset = (text.as_ptr() as usize)
+            .checked_sub(start)
+            .filter(|offset| offset + text.len() <= source.len())
+            .or_else(|| source.find(text))
+            .unwrap_or(0);
+
+        SolveError {
+            message: message.into(),
+            location: Some(Location {
+                line: line + 1,
+                column: source[..offset].chars().count() + 1,
+                text: text.to_owned(),
+                source: source.to_owned(),
+            }),
+            hint: None,
+        }
+    }
+
+    /// Render the error the way compilers do: the message, the offending line of the input
+    /// named `input` with carets under the text that was not understood, and the hint
+    pub fn snippet(&self, input: &str) -> String {
+        let mut lines = vec![format!("error: {}", self.message)];
+
+        if let Some(location) = &self.location {
+            let number = location.line.to_string();
+            let gutter = " ".repeat(number.len());
+            // Keep tabs so the carets line up with the text above them
+            let padding = location
+                .source
+                .chars()
+                .take(location.column - 1)
+                .map(|c| if c == '\t' { '\t' } else { ' ' })
+                .collect::<String>();
+            let carets = "^".repeat(location.text.chars().count().max(1));
+
+            lines.push(format!(
+                "{}--> {}:{}:{}",
+                gutter, input, location.line, location.column
+            ));
+            lines.push(format!("{} |", gutter));
+            lines.push(format!("{} | {}", number, location.source));
+            lines.push(format!("{} | {}{}", gutter, padding, carets));
+            if let Some(hint) = &self.hint {
+                lines.push(format!("{} = hint: {}", gutter, hint));
+            }
+        } else if let Some(hint) = &self.hint {
+            lines.push(format!("  = hint: {}", hint));
+        }
+
+        lines.join("\n")
+    }
+}
+
+impl Display for SolveError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> DisplayResult {
+        match &self.location {
+            Some(location) => write!(
+                f,
+                "line {}, column {}: {} (found '{}' in '{}')",
+                location.line, location.column, self.message, location.text, location.source
+            ),
+            None => write!(f, "{}", self.message),
+        }
+    }
+}
+
+impl std::error::Error for SolveError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve_error_location() {
+        let source = "A Q";
+        let err = SolveError::at(4, source, &source[2..], "Unknown symbol");
+        let location = err.location.as_ref().unwrap();
+
+        assert_eq!((location.line, location.column), (5, 3));
+        assert_eq!(location.text, "Q");
+        assert_eq!(
+            err.to_string(),
+            "line 5, column 3: Unknown symbol (found 'Q' in 'A Q')"
+        );
+
+        let err = SolveError::at(0, "ab€cd", "cd", "Searched");
+        assert_eq!(err.location.unwrap().column, 4);
+        assert_eq!(SolveError::new("No root").to_string(), "No root");
+    }
+
+    #[test]
+    fn test_snippet() {
+        let source = "move 1 frm 2 to 3";
+        let err = SolveError::at(11, source, &source[7..10], "Expected an instruction")
+            .with_hint("instructions look like 'move 1 from 2 to 3'");
+
+        assert_eq!(
+            err.snippet("input_5.txt"),
+            [
+                "error: Expected an instruction",
+                "  --> input_5.txt:12:8",
+                "   |",
+                "12 | move 1 frm 2 to 3",
+                "   |        ^^^",
+                "   = hint: instructions look like 'move 1 from 2 to 3'",
+            ]
+            .join("\n")
+        );
+
+        let err = SolveError::at(0, "\t[1", "[", "Never closed");
+        assert!(err.snippet("input").ends_with("1 | \t[1\n  | \t^"));
+        assert_eq!(
+            SolveError::new("No root").snippet("input"),
+            "error: No root"
+        );
+    }
+}
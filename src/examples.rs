@@ -0,0 +1,173 @@
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{answers::KnownAnswers, error::AdventError, store};
+
+/// Code blocks with fewer lines are usually a single value or instruction, not an example input
+const MIN_EXAMPLE_LINES: usize = 3;
+
+/// An example input from the puzzle description, with the answers it should give
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Example {
+    pub input: String,
+    pub silver: Option<String>,
+    pub gold: Option<String>,
+    /// Parameters that differ from the real input, like the row of day 15
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub params: Vec<(String, String)>,
+}
+
+impl Example {
+    /// The answers of the example, to check a run against like accepted answers
+    pub fn answers(&self) -> KnownAnswers {
+        KnownAnswers {
+            silver: self.silver.clone(),
+            gold: self.gold.clone(),
+        }
+    }
+}
+
+/// Pull the example out of a puzzle page: the first `<pre><code>` block of a few lines and,
+/// of every part, the last emphasized code, which is where the answer of the example is given
+pub fn extract(html: &str) -> Option<Example> {
+    let document = scraper::Html::parse_document(html);
+    let article_selector = scraper::Selector::parse("article.day-desc").unwrap();
+    let block_selector = scraper::Selector::parse("pre > code").unwrap();
+    let answer_selector = scraper::Selector::parse("code > em, em > code").unwrap();
+
+    let articles = document.select(&article_selector).collect::<Vec<_>>();
+    let blocks = articles
+        .first()?
+        .select(&block_selector)
+        .map(|block| block.text().collect::<String>())
+        .collect::<Vec<String>>();
+    let input = blocks
+        .iter()
+        .find(|block| block.lines().count() >= MIN_EXAMPLE_LINES)
+        .or_else(|| blocks.iter().max_by_key(|block| block.len()))?;
+
+    let answer = |part: usize| {
+        articles.get(part).and_then(|article| {
+            article
+                .select(&answer_selector)
+                .last()
+                .map(|answer| answer.text().collect::<String>().trim().to_owned())
+        })
+    };
+
+    Some(Example {
+        input: input.to_owned(),
+        silver: answer(0),
+        gold: answer(1),
+        params: Vec::new(),
+    })
+}
+
+/// The examples of every day of a year, stored per day in `src/data/<year>/examples/day_<day>.json`
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExampleStore {
+    examples: Vec<Example>,
+}
+
+impl ExampleStore {
+    fn location(data_dir: &Path, day: u8) -> PathBuf {
+        data_dir.join("examples").join(format!("day_{}.json", day))
+    }
+
+    pub fn load(data_dir: &Path, day: u8) -> Result<ExampleStore, AdventError> {
+        store::load(&ExampleStore::location(data_dir, day))
+    }
+
+    pub fn save(&self, data_dir: &Path, day: u8) -> Result<(), AdventError> {
+        store::save(&ExampleStore::location(data_dir, day), self)
+    }
+
+    /// The example numbered `number`, starting at 1 like they are shown to users
+    pub fn get(&self, number: usize) -> Option<&Example> {
+        number.checked_sub(1).and_then(|i| self.examples.get(i))
+    }
+
+    pub fn len(&self) -> usize {
+        self.examples.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.examples.is_empty()
+    }
+
+    pub fn push(&mut self, example: Example) {
+        self.examples.push(example);
+    }
+
+    /// Replace the first example with a freshly extracted one, keeping the parameters that were set for it
+    pub fn update(&mut self, mut example: Example) {
+        match self.examples.first_mut() {
+            Some(first) => {
+                example.params = std::mem::take(&mut first.params);
+                *first = example;
+            }
+            None => self.examples.push(example),
+        }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Example> {
+        self.examples.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_store() {
+        let dir = std::env::temp_dir().join(format!("advent_examples_{}", std::process::id()));
+        assert!(ExampleStore::load(&dir, 1).unwrap().is_empty());
+
+        let mut store = ExampleStore::default();
+        store.push(Example {
+            input: String::from("1000\n2000\n"),
+            silver: Some(String::from("3000")),
+            ..Default::default()
+        });
+        store.save(&dir, 1).unwrap();
+
+        let loaded = ExampleStore::load(&dir, 1).unwrap();
+        assert_eq!(loaded, store);
+        assert_eq!(loaded.get(1).unwrap().input, "1000\n2000\n");
+        assert!(loaded.get(0).is_none() && loaded.get(2).is_none());
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_extract() {
+        let html = "<main><article class=\"day-desc\"><h2>--- Day 1: Calorie Counting ---</h2>\
+            <p>For example:</p><pre><code>1\n</code></pre>\
+            <pre><code>1000\n2000\n\n4000\n</code></pre>\
+            <p>Find the Elf carrying the most Calories, <code><em>4000</em></code>.</p></article>\
+            <p>Your puzzle answer was <code>70000</code>.</p>\
+            <article class=\"day-desc\"><h2 id=\"part2\">--- Part Two ---</h2>\
+            <p>The top three carry <em>a lot</em>, <em><code>7000</code></em>.</p></article></main>";
+        let example = extract(html).unwrap();
+
+        assert_eq!(example.input, "1000\n2000\n\n4000\n");
+        assert_eq!(example.silver.as_deref(), Some("4000"));
+        assert_eq!(example.gold.as_deref(), Some("7000"));
+
+        let silver_only = html.split("<p>Your puzzle").next().unwrap();
+        assert_eq!(extract(silver_only).unwrap().gold, None);
+        assert_eq!(extract("<main></main>"), None);
+
+        let mut store = ExampleStore::default();
+        store.push(Example {
+            params: vec![(String::from("row"), String::from("10"))],
+            ..Default::default()
+        });
+        store.update(example);
+        assert_eq!(store.len(), 1);
+        assert_eq!(store.get(1).unwrap().params.len(), 1);
+        assert_eq!(store.get(1).unwrap().silver.as_deref(), Some("4000"));
+    }
+}
@@ -0,0 +1,180 @@
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{error::AdventError, store, submission::SubmissionResult};
+
+/// Answers that are never worth submitting, like the `-1` of the assignment template
+const PLACEHOLDERS: [&str; 2] = ["", "-1"];
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Attempt {
+    pub answer: String,
+    pub result: SubmissionResult,
+}
+
+/// Everything that was learned from the submissions of a single level
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LevelHistory {
+    pub attempts: Vec<Attempt>,
+    /// The highest answer that was too low
+    pub lower_bound: Option<i128>,
+    /// The lowest answer that was too high
+    pub upper_bound: Option<i128>,
+}
+
+impl LevelHistory {
+    fn check(&self, answer: &str) -> Result<(), String> {
+        if PLACEHOLDERS.contains(&answer) {
+            return Err(format!("'{}' is a placeholder answer", answer));
+        }
+        // Letters that are not recognized show as their picture, which is never the answer
+        if answer.contains('\n') {
+            return Err(String::from("could not read the letters"));
+        }
+
+        for attempt in &self.attempts {
+            if attempt.result == SubmissionResult::Correct {
+                return Err(format!("Already solved with {}", attempt.answer));
+            }
+            if attempt.answer == answer && attempt.result.is_wrong() {
+                return Err(format!(
+                    "{} was already submitted: {}",
+                    answer, attempt.result
+                ));
+            }
+        }
+
+        if let Ok(value) = answer.parse::<i128>() {
+            if let Some(lower) = self.lower_bound.filter(|lower| value <= *lower) {
+                return Err(format!(
+                    "{} is not higher than {}, which is too low",
+                    value, lower
+                ));
+            }
+            if let Some(upper) = self.upper_bound.filter(|upper| value >= *upper) {
+                return Err(format!(
+                    "{} is not lower than {}, which is too high",
+                    value, upper
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn record(&mut self, answer: &str, result: SubmissionResult) {
+        let value = answer.parse::<i128>().ok();
+        match result {
+            SubmissionResult::TooHigh => {
+                self.upper_bound = value.into_iter().chain(self.upper_bound).min();
+            }
+            SubmissionResult::TooLow => {
+                self.lower_bound = value.into_iter().chain(self.lower_bound).max();
+            }
+            _ => (),
+        }
+
+        self.attempts.push(Attempt {
+            answer: answer.to_owned(),
+            result,
+        });
+    }
+}
+
+/// Persistent log of submitted answers per day and level, stored in `src/data/<year>/submissions.json`
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SubmissionHistory {
+    days: BTreeMap<u8, BTreeMap<u8, LevelHistory>>,
+}
+
+impl SubmissionHistory {
+    fn location(data_dir: &Path) -> PathBuf {
+        data_dir.join("submissions.json")
+    }
+
+    /// Load the history from disk, starting empty when nothing was submitted yet
+    pub fn load(data_dir: &Path) -> Result<SubmissionHistory, AdventError> {
+        store::load(&SubmissionHistory::location(data_dir))
+    }
+
+    pub fn save(&self, data_dir: &Path) -> Result<(), AdventError> {
+        store::save(&SubmissionHistory::location(data_dir), self)
+    }
+
+    pub fn level(&self, day: u8, level: u8) -> Option<&LevelHistory> {
+        self.days.get(&day)?.get(&level)
+    }
+
+    /// Refuse answers that are placeholders, known to be wrong or outside the learned bounds
+    pub fn check(&self, day: u8, level: u8, answer: &str) -> Result<(), AdventError> {
+        match self.level(day, level) {
+            Some(history) => history.check(answer),
+            None => LevelHistory::default().check(answer),
+        }
+        .map_err(AdventError::Refused)
+    }
+
+    pub fn record(&mut self, day: u8, level: u8, answer: &str, result: SubmissionResult) {
+        self.days
+            .entry(day)
+            .or_default()
+            .entry(level)
+            .or_default()
+            .record(answer, result);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_placeholders() {
+        let history = SubmissionHistory::default();
+        assert!(history.check(1, 1, "-1").is_err());
+        assert!(history.check(1, 1, "").is_err());
+        assert!(history.check(1, 1, "24000").is_ok());
+
+        let unknown = crate::ocr::Letters::new(String::from("██  ██\n██  ██"));
+        assert!(matches!(
+            history.check(10, 2, &unknown.to_string()),
+            Err(AdventError::Refused(reason)) if reason == "could not read the letters"
+        ));
+    }
+
+    #[test]
+    fn test_known_wrong() {
+        let mut history = SubmissionHistory::default();
+        history.record(1, 1, "abc", SubmissionResult::Incorrect);
+        assert!(history.check(1, 1, "abc").is_err());
+        assert!(history.check(1, 2, "abc").is_ok());
+        assert!(history.check(1, 1, "abd").is_ok());
+    }
+
+    #[test]
+    fn test_bounds() {
+        let mut history = SubmissionHistory::default();
+        history.record(1, 1, "100", SubmissionResult::TooLow);
+        history.record(1, 1, "200", SubmissionResult::TooHigh);
+        history.record(1, 1, "300", SubmissionResult::TooHigh);
+
+        let level = history.level(1, 1).unwrap();
+        assert_eq!(level.lower_bound, Some(100));
+        assert_eq!(level.upper_bound, Some(200));
+
+        assert!(history.check(1, 1, "100").is_err());
+        assert!(history.check(1, 1, "250").is_err());
+        assert!(history.check(1, 1, "150").is_ok());
+    }
+
+    #[test]
+    fn test_already_solved() {
+        let mut history = SubmissionHistory::default();
+        history.record(1, 1, "150", SubmissionResult::Correct);
+        assert!(history.check(1, 1, "151").is_err());
+    }
+}
@@ -0,0 +1,198 @@
+use std::{
+    io,
+    path::{Path, PathBuf},
+};
+
+use sha2::{Digest, Sha256};
+
+use crate::error::AdventError;
+
+/// Where the input of a run comes from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    /// The puzzle input of the account, downloaded once and cached
+    Puzzle,
+    File(PathBuf),
+    Stdin,
+    /// An example from the puzzle description, numbered from 1
+    Example(usize),
+}
+
+impl Source {
+    /// The source chosen by `--input` and `--example`, of which `--input -` reads stdin
+    pub fn from_args(path: Option<&Path>, example: Option<usize>) -> Source {
+        match (path, example) {
+            (_, Some(number)) => Source::Example(number),
+            (Some(path), None) if path == Path::new("-") => Source::Stdin,
+            (Some(path), None) => Source::File(path.to_owned()),
+            (None, None) => Source::Puzzle,
+        }
+    }
+
+    /// Only answers for the puzzle input of the account are worth submitting
+    pub fn is_puzzle(&self) -> bool {
+        *self == Source::Puzzle
+    }
+
+    /// How the input is called in diagnostics
+    pub fn name(&self, day: u8) -> String {
+        match self {
+            Source::Puzzle => format!("input of day {}", day),
+            Source::File(path) => path.display().to_string(),
+            Source::Stdin => String::from("<stdin>"),
+            Source::Example(number) => format!("example {} of day {}", number, day),
+        }
+    }
+}
+
+/// Check that a response of the input endpoint holds a genuine puzzle input
+pub fn check(
+    day: u8,
+    status: u16,
+    content_type: Option<&str>,
+    body: &str,
+) -> Result<(), AdventError> {
+    if body.contains("Please log in") {
+        return Err(AdventError::NotLoggedIn);
+    }
+    if status == 404 || body.contains("before it unlocks") {
+        return Err(AdventError::NotUnlocked(day));
+    }
+    if status != 200 {
+        return Err(AdventError::UnexpectedStatus(status));
+    }
+    if body.trim().is_empty() {
+        return Err(AdventError::InvalidInput(String::from("it is empty")));
+    }
+    if is_html(content_type, body) {
+        return Err(AdventError::InvalidInput(String::from(
+            "received an HTML page",
+        )));
+    }
+
+    Ok(())
+}
+
+/// Inputs can start with `<` themselves, like the jet pattern of 2022 day 17, so only the
+/// content type or a document header give a page away
+fn is_html(content_type: Option<&str>, body: &str) -> bool {
+    let start = body.trim_start().to_ascii_lowercase();
+    content_type.is_some_and(|content_type| content_type.contains("text/html"))
+        || start.starts_with("<!doctype")
+        || start.starts_with("<html")
+}
+
+/// Read a cached input, as long as it still matches the hash stored next to it. Inputs that
+/// were cached before hashes were kept are read as they are, without writing anything.
+pub fn read_cached(path: &Path) -> Option<String> {
+    let contents = std::fs::read_to_string(path).ok()?;
+    match std::fs::read_to_string(hash_location(path)) {
+        Ok(expected) => (expected.trim() == hash(&contents)).then_some(contents),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Some(contents),
+        Err(_) => None,
+    }
+}
+
+/// Give an input that was cached before hashes were kept its hash, so a later change is noticed
+pub fn store_missing_hash(path: &Path, contents: &str) -> io::Result<()> {
+    let location = hash_location(path);
+    if location.exists() {
+        return Ok(());
+    }
+    std::fs::write(location, hash(contents))
+}
+
+/// Cache an input together with its hash
+pub fn write_cached(path: &Path, contents: &str) -> io::Result<()> {
+    std::fs::write(path, contents)?;
+    std::fs::write(hash_location(path), hash(contents))
+}
+
+pub fn hash(contents: &str) -> String {
+    Sha256::digest(contents.as_bytes())
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+fn hash_location(path: &Path) -> PathBuf {
+    let mut location = path.as_os_str().to_owned();
+    location.push(".sha256");
+    PathBuf::from(location)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check() {
+        assert!(check(1, 200, Some("text/plain"), "1000\n2000\n").is_ok());
+        assert!(check(17, 200, Some("text/plain"), "<<><>>><<<>>\n").is_ok());
+        assert!(matches!(
+            check(
+                1,
+                400,
+                None,
+                "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n"
+            ),
+            Err(AdventError::NotLoggedIn)
+        ));
+        assert!(matches!(
+            check(
+                25,
+                404,
+                None,
+                "Please don't repeatedly request this endpoint before it unlocks!"
+            ),
+            Err(AdventError::NotUnlocked(25))
+        ));
+        assert!(matches!(
+            check(1, 500, None, "Internal Server Error"),
+            Err(AdventError::UnexpectedStatus(500))
+        ));
+        assert!(matches!(
+            check(1, 200, None, "<!DOCTYPE html>\n<html></html>"),
+            Err(AdventError::InvalidInput(_))
+        ));
+        assert!(matches!(
+            check(17, 200, Some("text/html; charset=utf-8"), "<<>\n"),
+            Err(AdventError::InvalidInput(_))
+        ));
+    }
+
+    #[test]
+    fn test_source() {
+        assert_eq!(Source::from_args(None, None), Source::Puzzle);
+        assert_eq!(Source::from_args(Some(Path::new("-")), None), Source::Stdin);
+        assert_eq!(
+            Source::from_args(Some(Path::new("friend.txt")), None),
+            Source::File(PathBuf::from("friend.txt"))
+        );
+        assert_eq!(Source::from_args(None, Some(2)), Source::Example(2));
+        assert!(Source::Puzzle.is_puzzle() && !Source::Example(1).is_puzzle());
+        assert_eq!(Source::Example(2).name(5), "example 2 of day 5");
+    }
+
+    #[test]
+    fn test_cache() {
+        let path = std::env::temp_dir().join(format!("advent_input_{}.txt", std::process::id()));
+        write_cached(&path, "1000\n2000\n").unwrap();
+        assert_eq!(read_cached(&path), Some(String::from("1000\n2000\n")));
+
+        std::fs::write(&path, "1000\n20").unwrap();
+        assert_eq!(read_cached(&path), None);
+
+        std::fs::remove_file(hash_location(&path)).unwrap();
+        assert_eq!(read_cached(&path), Some(String::from("1000\n20")));
+        assert!(!hash_location(&path).exists());
+
+        store_missing_hash(&path, "1000\n20").unwrap();
+        assert_eq!(read_cached(&path), Some(String::from("1000\n20")));
+        store_missing_hash(&path, "other").unwrap();
+        assert_eq!(read_cached(&path), Some(String::from("1000\n20")));
+
+        let _ = std::fs::remove_file(hash_location(&path));
+        let _ = std::fs::remove_file(&path);
+    }
+}
@@ -1,245 +1,602 @@
-mod assignment_1;
-mod assignment_10;
-mod assignment_11;
-mod assignment_12;
-mod assignment_13;
-mod assignment_14;
-mod assignment_15;
-mod assignment_16;
-mod assignment_17;
-mod assignment_18;
-mod assignment_19;
-mod assignment_2;
-mod assignment_20;
-mod assignment_21;
-mod assignment_22;
-mod assignment_3;
-mod assignment_4;
-mod assignment_5;
-mod assignment_6;
-mod assignment_7;
-mod assignment_8;
-mod assignment_9;
-
-use reqwest::{
-    header::{HeaderMap, HeaderValue, CONTENT_TYPE, COOKIE, USER_AGENT},
-    redirect::Policy,
-    Client,
-};
+pub mod answers;
+pub mod bench;
+pub mod cli;
+pub mod client;
+pub mod description;
+pub mod error;
+pub mod examples;
+pub mod history;
+pub mod input;
+pub mod mock;
+pub mod ocr;
+pub mod params;
+pub mod parse;
+pub mod perf;
+pub mod queue;
+pub mod registry;
+pub mod report;
+pub mod scaffold;
+pub mod store;
+pub mod submission;
+pub mod summary;
+#[cfg(test)]
+mod testing;
+pub mod year_2022;
+
+use answers::{AnswerStore, Verdict};
+use clap::ValueEnum;
+use client::AocClient;
+use error::{AdventError, SolveError};
+use examples::{Example, ExampleStore};
+use input::Source;
+use params::Params;
+use queue::{QueuedAnswer, SubmissionQueue};
+use report::Reporter;
+use submission::SubmissionResult;
+use summary::DayReport;
+
 use std::{
     fmt::{Display, Formatter, Result as DisplayResult},
-    io,
-    time::Instant,
+    io::{self, Write},
+    path::PathBuf,
+    time::{Duration, Instant},
 };
 
-pub fn solve(day: u8) {
-    let raw_input = get_input(&day);
-    let (silver, gold) = match day {
-        1 => assignment_1::Solution::new().run(raw_input),
-        2 => assignment_2::Solution::new().run(raw_input),
-        3 => assignment_3::Solution::new().run(raw_input),
-        4 => assignment_4::Solution::new().run(raw_input),
-        5 => assignment_5::Solution::new().run(raw_input),
-        6 => assignment_6::Solution::new().run(raw_input),
-        7 => assignment_7::Solution::new().run(raw_input),
-        8 => assignment_8::Solution::new().run(raw_input),
-        9 => assignment_9::Solution::new().run(raw_input),
-        10 => assignment_10::Solution::new().run(raw_input),
-        11 => assignment_11::Solution::new().run(raw_input),
-        12 => assignment_12::Solution::new().run(raw_input),
-        13 => assignment_13::Solution::new().run(raw_input),
-        14 => assignment_14::Solution::new().run(raw_input),
-        15 => assignment_15::Solution::new().run(raw_input),
-        16 => assignment_16::Solution::new().run(raw_input),
-        17 => assignment_17::Solution::new().run(raw_input),
-        18 => assignment_18::Solution::new().run(raw_input),
-        19 => assignment_19::Solution::new().run(raw_input),
-        20 => assignment_20::Solution::new().run(raw_input),
-        21 => assignment_21::Solution::new().run(raw_input),
-        22 => assignment_22::Solution::new().run(raw_input),
-        d => panic!("Day {} has not been solved yet", d),
+/// The year that is used when no year is given
+pub const DEFAULT_YEAR: u16 = 2022;
+
+/// Read the year from the `ADVENT_YEAR` environment variable, falling back to [`DEFAULT_YEAR`]
+pub fn year_from_env() -> u16 {
+    dotenv::var("ADVENT_YEAR")
+        .ok()
+        .and_then(|year| year.trim().parse().ok())
+        .unwrap_or(DEFAULT_YEAR)
+}
+
+/// The answers of a day together with how many nanoseconds each phase took
+#[derive(Debug, Clone)]
+pub struct RunResult<O = Output> {
+    pub silver: O,
+    pub gold: O,
+    pub parse_time: u128,
+    pub silver_time: u128,
+    pub gold_time: u128,
+}
+
+impl<O> RunResult<O> {
+    pub fn total_time(&self) -> u128 {
+        self.parse_time + self.silver_time + self.gold_time
+    }
+}
+
+/// Solve a day with the parameters named in `overrides` set to other values than the defaults
+pub fn run(
+    year: u16,
+    day: u8,
+    raw_input: String,
+    overrides: &[(String, String)],
+) -> Result<RunResult, AdventError> {
+    let registered = registry::find(year, day).ok_or(AdventError::NotSolved(year, day))?;
+
+    Ok(registered.solve_with(raw_input, overrides)?)
+}
+
+/// Read the input of a day from wherever `source` points at
+pub fn read_input(year: u16, day: u8, source: &Source) -> Result<String, AdventError> {
+    match source {
+        Source::Puzzle => get_input(year, day),
+        Source::File(path) => Ok(std::fs::read_to_string(path)?),
+        Source::Stdin => Ok(io::read_to_string(io::stdin())?),
+        Source::Example(number) => Ok(load_example(year, day, *number)?.input),
+    }
+}
+
+/// An example of a day as it was extracted from the puzzle page, numbered from 1
+pub fn load_example(year: u16, day: u8, number: usize) -> Result<Example, AdventError> {
+    let client = AocClient::from_env()?;
+    let examples = ExampleStore::load(&client.year_dir(year)?, day)?;
+    examples
+        .get(number)
+        .cloned()
+        .ok_or(AdventError::NoExample(day, number))
+}
+
+/// The parameters an input needs, like those stored with an example, followed by `overrides`
+pub fn input_overrides(
+    year: u16,
+    day: u8,
+    source: &Source,
+    overrides: &[(String, String)],
+) -> Result<Vec<(String, String)>, AdventError> {
+    let mut all = match source {
+        Source::Example(number) => load_example(year, day, *number)?.params,
+        _ => Vec::new(),
     };
+    all.extend_from_slice(overrides);
+    Ok(all)
+}
+
+/// Why answers of a run should not be submitted, if there is a reason
+fn not_submittable(source: &Source, overrides: &[(String, String)]) -> Option<&'static str> {
+    if !source.is_puzzle() {
+        Some("the input is not the puzzle input of the account")
+    } else if !overrides.is_empty() {
+        Some("the answers were found with overridden parameters")
+    } else {
+        None
+    }
+}
+
+/// Decides which answer, if any, is submitted after solving a day
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum SubmitPolicy {
+    /// Ask on stdin which answer should be submitted
+    Ask,
+    /// Never submit an answer
+    Never,
+    /// Always submit the silver answer
+    Silver,
+    /// Always submit the gold answer
+    Gold,
+    /// Submit the answer of the first part that has not been solved yet
+    Unsolved,
+}
+
+impl SubmitPolicy {
+    /// Read the policy from the `ADVENT_SUBMIT` environment variable
+    pub fn from_env() -> Option<SubmitPolicy> {
+        dotenv::var("ADVENT_SUBMIT")
+            .ok()
+            .and_then(|value| SubmitPolicy::from_str(value.trim(), true).ok())
+    }
+
+    fn choose_level(&self, year: u16, day: u8) -> Result<Option<u8>, AdventError> {
+        let level = match self {
+            SubmitPolicy::Never => None,
+            SubmitPolicy::Silver => Some(1),
+            SubmitPolicy::Gold => Some(2),
+            SubmitPolicy::Unsolved => match AocClient::from_env()?.solved_levels(year, day)? {
+                0 => Some(1),
+                1 => Some(2),
+                _ => {
+                    eprintln!("Both parts of day {} are already solved", day);
+                    None
+                }
+            },
+            SubmitPolicy::Ask => {
+                let mut user_input = String::new();
+                let stdin = io::stdin();
+                eprintln!(
+                    "Which answer would you like to commit? ({}{}s{}ilver/{}{}g{}old)",
+                    termion::style::Underline,
+                    termion::style::Bold,
+                    termion::style::Reset,
+                    termion::style::Underline,
+                    termion::style::Bold,
+                    termion::style::Reset,
+                );
+                stdin.read_line(&mut user_input)?;
+
+                match user_input.trim() {
+                    "s" | "silver" => Some(1),
+                    "g" | "gold" => Some(2),
+                    _ => {
+                        eprintln!("nothing usefull");
+                        None
+                    }
+                }
+            }
+        };
 
-    let mut user_input = String::new();
-    let stdin = io::stdin();
-    println!(
-        "Which answer would you like to commit? ({}{}s{}ilver/{}{}g{}old)",
-        termion::style::Underline,
-        termion::style::Bold,
-        termion::style::Reset,
-        termion::style::Underline,
-        termion::style::Bold,
-        termion::style::Reset,
-    );
-    stdin.read_line(&mut user_input).unwrap();
-
-    let (level, answer) = match user_input.trim() {
-        "s" | "silver" => (1, silver),
-        "g" | "gold" => (2, gold),
-        _ => {
-            println!("nothing usefull");
-            std::process::exit(0)
+        Ok(level)
+    }
+}
+
+/// Solve a day, report the answers and submit one of them as `policy` decides,
+/// returning what Advent of Code thought of it
+pub fn solve(
+    year: u16,
+    day: u8,
+    source: &Source,
+    overrides: &[(String, String)],
+    policy: SubmitPolicy,
+    rate_limit: RateLimitPolicy,
+    reporter: &mut dyn Reporter,
+) -> Result<Option<SubmissionResult>, AdventError> {
+    let raw_input = read_input(year, day, source)?;
+    let registered = registry::find(year, day).ok_or(AdventError::NotSolved(year, day))?;
+    let result = registered
+        .solve_with(raw_input, &input_overrides(year, day, source, overrides)?)
+        .map_err(|err| AdventError::Solve(source.name(day), Box::new(err)))?;
+    reporter.day(DayReport::new(registered, Ok(result.clone())))?;
+    reporter.finish()?;
+
+    if let Some(reason) = not_submittable(source, overrides) {
+        if policy != SubmitPolicy::Never {
+            eprintln!("Not submitting, {}", reason);
         }
+        return Ok(None);
+    }
+
+    let RunResult { silver, gold, .. } = result;
+
+    let (level, answer) = match policy.choose_level(year, day)? {
+        Some(1) => (1, silver),
+        Some(_) => (2, gold),
+        None => return Ok(None),
     };
 
-    send_answer(day, level, answer);
+    submit_answer(year, day, level, &answer, rate_limit).map(Some)
 }
 
-pub trait Assignment {
-    type Input;
-    type Output: Display;
+/// Run every registered day of a year in sequence and report all results together.
+/// A day that panics or has no input is reported instead of ending the run.
+pub fn solve_all(year: u16, reporter: &mut dyn Reporter) -> Result<(), AdventError> {
+    for registered in registry::days_of(year) {
+        let result = read_input(year, registered.day, &Source::Puzzle)
+            .map_err(|err| err.to_string())
+            .and_then(|input| summary::catch_solve(registered, input));
+        reporter.day(DayReport::new(registered, result))?;
+    }
+
+    Ok(reporter.finish()?)
+}
 
-    fn parse_input(&self, input: &str) -> Option<Self::Input>;
+/// Run every day of a year, or only `day`, on its cached input and compare the answers
+/// with the ones Advent of Code accepted before. Fails when any day gives another answer.
+pub fn verify(year: u16, day: Option<u8>, reporter: &mut dyn Reporter) -> Result<(), AdventError> {
+    let client = AocClient::from_env()?;
+    let answers = AnswerStore::load(&client.year_dir(year)?)?;
+    let mut failures = 0;
+
+    for registered in registry::days_of(year).filter(|d| day.is_none_or(|day| d.day == day)) {
+        let report = match (
+            answers.get(registered.day),
+            client.cached_input(year, registered.day),
+        ) {
+            (None, _) => skipped(registered, "no accepted answers, store them with `seed`"),
+            (_, None) => skipped(registered, "no cached input"),
+            (Some(known), Some(input)) => match summary::catch_solve(registered, input) {
+                Ok(result) => {
+                    let verdict = Verdict::check(known, &result);
+                    DayReport::new(registered, Ok(result)).with_verdict(verdict)
+                }
+                Err(reason) => DayReport::new(registered, Err(reason.clone()))
+                    .with_verdict(Verdict::Failed(reason)),
+            },
+        };
 
-    fn silver(&self, input: &Self::Input) -> Option<Self::Output>;
-    fn gold(&self, input: &Self::Input) -> Option<Self::Output>;
+        if report.is_failure() {
+            failures += 1;
+        }
+        reporter.day(report)?;
+    }
+    reporter.finish()?;
 
-    fn timed_silver(&self, input: &Self::Input) -> Option<(Self::Output, u128)> {
-        let before = Instant::now();
-        let solution = self.silver(input)?;
+    match failures {
+        0 => Ok(()),
+        failures => Err(AdventError::VerifyFailed(failures)),
+    }
+}
+
+/// Store the accepted answers of every registered day of a year, or only `day`, from their
+/// puzzle pages. Days solved before the answer store existed have nothing to verify without it.
+pub fn seed_answers(year: u16, day: Option<u8>) -> Result<(), AdventError> {
+    let client = AocClient::from_env()?;
 
-        Some((solution, before.elapsed().as_micros()))
+    for registered in registry::days_of(year).filter(|d| day.is_none_or(|day| d.day == day)) {
+        let known = client.seed_answers(year, registered.day)?;
+        println!(
+            "Day {}: silver {}, gold {}",
+            registered.day,
+            known.silver.as_deref().unwrap_or("not solved"),
+            known.gold.as_deref().unwrap_or("not solved")
+        );
     }
 
-    fn timed_gold(&self, input: &Self::Input) -> Option<(Self::Output, u128)> {
-        let before = Instant::now();
-        let solution = self.gold(input)?;
+    Ok(())
+}
 
-        Some((solution, before.elapsed().as_micros()))
+fn skipped(registered: &registry::Day, reason: &str) -> DayReport {
+    DayReport::new(registered, Err(reason.to_owned()))
+        .with_verdict(Verdict::Skipped(reason.to_owned()))
+}
+
+/// Solve a day and submit the answer of a single `level` (1 for silver, 2 for gold)
+pub fn submit(
+    year: u16,
+    day: u8,
+    level: u8,
+    source: &Source,
+    overrides: &[(String, String)],
+    rate_limit: RateLimitPolicy,
+) -> Result<SubmissionResult, AdventError> {
+    if let Some(reason) = not_submittable(source, overrides) {
+        return Err(AdventError::Refused(reason.to_owned()));
     }
+    let RunResult { silver, gold, .. } = run(year, day, read_input(year, day, source)?, &[])?;
+    let answer = if level == 1 { silver } else { gold };
 
-    fn run(&self, input: String) -> (Self::Output, Self::Output) {
-        let parsed_silver = self
-            .parse_input(&input)
-            .expect("Could not parse silver input");
-        let parsed_gold = self
-            .parse_input(&input)
-            .expect("Could not parse gold input");
-        let (silver_answer, silver_time) = self
-            .timed_silver(&parsed_silver)
-            .expect("Error while solving silver");
-        let (gold_answer, gold_time) = self
-            .timed_gold(&parsed_gold)
-            .expect("Error while solving gold");
-        println!(
-            "----------\n| Silver | {} ({} µs)\n----------\n| Gold   | {} ({} µs)\n----------\n",
-            silver_answer, silver_time, gold_answer, gold_time
+    eprintln!("Submitting {} for day {} level {}", answer, day, level);
+    submit_answer(year, day, level, &answer, rate_limit)
+}
+
+/// How often `--on-rate-limit wait` waits and submits again before giving up
+const MAX_RATE_LIMIT_RETRIES: usize = 3;
+
+/// What to do when Advent of Code asks to wait before answering again
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum RateLimitPolicy {
+    /// Only report how long to wait
+    Report,
+    /// Count down the wait and submit again
+    Wait,
+    /// Queue the answer so a later invocation submits it
+    Queue,
+}
+
+/// Submit an answer, handling a rate limit as `rate_limit` says. A rate limit that outlasts
+/// the retries, or is only reported or queued, comes back as [`SubmissionResult::RateLimited`].
+fn submit_answer(
+    year: u16,
+    day: u8,
+    level: u8,
+    answer: impl Display,
+    rate_limit: RateLimitPolicy,
+) -> Result<SubmissionResult, AdventError> {
+    let answer = answer.to_string();
+    let mut retries = 0;
+
+    loop {
+        let wait = match send_answer(year, day, level, &answer)? {
+            SubmissionResult::RateLimited(wait) => wait,
+            result => return Ok(result),
+        };
+
+        match rate_limit {
+            RateLimitPolicy::Wait if retries < MAX_RATE_LIMIT_RETRIES => {
+                retries += 1;
+                countdown(wait);
+            }
+            RateLimitPolicy::Queue => {
+                let dir = data_dir()?;
+                let mut queue = SubmissionQueue::load(&dir)?;
+                queue.push(QueuedAnswer::new(year, day, level, answer, wait));
+                queue.save(&dir)?;
+                eprintln!("Queued, submit again in {}s", wait.as_secs());
+                return Ok(SubmissionResult::RateLimited(wait));
+            }
+            _ => return Ok(SubmissionResult::RateLimited(wait)),
+        }
+    }
+}
+
+fn countdown(wait: Duration) {
+    let mut stderr = io::stderr();
+    for remaining in (1..=wait.as_secs() + 1).rev() {
+        eprint!(
+            "\r{}Rate limited, submitting again in {}s",
+            termion::clear::CurrentLine,
+            remaining
+        );
+        let _ = stderr.flush();
+        std::thread::sleep(Duration::from_secs(1));
+    }
+    eprintln!("\r{}", termion::clear::CurrentLine);
+}
+
+/// Submit every queued answer of which the wait is over. An answer that is rate limited
+/// again or could not be sent goes back into the queue, and the first error is returned.
+pub fn flush_queue(rate_limit: RateLimitPolicy) -> Result<(), AdventError> {
+    let dir = data_dir()?;
+    let mut queue = SubmissionQueue::load(&dir)?;
+    let due = queue.take_due();
+    queue.save(&dir)?;
+
+    for queued in queue.entries() {
+        eprintln!(
+            "{} day {} level {} stays queued for {}s",
+            queued.year,
+            queued.day,
+            queued.level,
+            queued.remaining().as_secs()
         );
+    }
+
+    let mut first_error = None;
+    for queued in due {
+        eprintln!(
+            "Submitting queued {} for {} day {} level {}",
+            queued.answer, queued.year, queued.day, queued.level
+        );
+        let wait = match submit_answer(
+            queued.year,
+            queued.day,
+            queued.level,
+            &queued.answer,
+            rate_limit,
+        ) {
+            Ok(SubmissionResult::RateLimited(wait)) => wait,
+            Ok(result) => {
+                eprintln!("{}", result);
+                continue;
+            }
+            // The history refuses this answer for good, queueing it again won't help
+            Err(err @ AdventError::Refused(_)) => {
+                eprintln!("{}", err);
+                first_error.get_or_insert(err);
+                continue;
+            }
+            Err(err) => {
+                eprintln!("{}", err);
+                first_error.get_or_insert(err);
+                Duration::ZERO
+            }
+        };
+
+        let mut queue = SubmissionQueue::load(&dir)?;
+        queue.push(QueuedAnswer::new(
+            queued.year,
+            queued.day,
+            queued.level,
+            queued.answer,
+            wait,
+        ));
+        queue.save(&dir)?;
+        eprintln!("Kept in the queue, submit again in {}s", wait.as_secs());
+    }
 
-        (silver_answer, gold_answer)
+    match first_error {
+        Some(err) => Err(err),
+        None => Ok(()),
     }
 }
 
-fn build_client(session_cookie: &str, content_type: &str) -> Result<Client, String> {
-    let cookie_header = HeaderValue::from_str(&format!("session={}", session_cookie.trim()))
-        .map_err(|err| format!("Invalid session cookie: {}", err))?;
-    let content_type_header = HeaderValue::from_str(content_type).unwrap();
-    let user_agent =
-        String::from("User-Agent: github.com/rboortman/advent-2022 by ron@techforce1.nl");
-    let user_agent_header = HeaderValue::from_str(&user_agent).unwrap();
-
-    let mut headers = HeaderMap::new();
-    headers.insert(COOKIE, cookie_header);
-    headers.insert(CONTENT_TYPE, content_type_header);
-    headers.insert(USER_AGENT, user_agent_header);
-
-    Client::builder()
-        .default_headers(headers)
-        .redirect(Policy::none())
-        .build()
-        .map_err(|err| err.to_string())
-}
-
-#[tokio::main]
-async fn get_input(assignment_id: &u8) -> String {
-    let mut data_location = project_root::get_project_root().unwrap();
-    data_location.push("src");
-    data_location.push("data");
-
-    std::fs::create_dir_all(&data_location).expect("Could not create data dir");
-    data_location.push(format!("input_{}.txt", assignment_id));
-
-    let contents = match std::fs::read_to_string(&data_location) {
-        Ok(c) => c,
-        Err(_) => {
-            let client = build_client(
-                dotenv::var("ADVENT_SESSION_KEY").unwrap().as_str(),
-                "text/plain",
-            )
-            .unwrap();
-
-            // let client = reqwest::Client::new();
-            let contents = client
-                .get(format!(
-                    "https://adventofcode.com/2022/day/{}/input",
-                    assignment_id
-                ))
-                .send()
-                .await
-                .unwrap()
-                .text()
-                .await
-                .unwrap();
-
-            let _ = std::fs::write(&data_location, &contents);
-            contents
+/// Generate and register the module of a day, filled in with the example of its puzzle page,
+/// and download its input. Without the page the module is generated from the bare template.
+pub fn new_day(year: u16, day: u8) -> Result<PathBuf, AdventError> {
+    let client = AocClient::from_env()?;
+    let page = match client.get_puzzle_page(year, day) {
+        Ok(page) => Some(page),
+        Err(err) => {
+            eprintln!("Could not download the puzzle page: {}", err);
+            None
         }
     };
 
-    contents
-}
-
-#[tokio::main]
-async fn send_answer(day: u8, level: u8, answer: Output) {
-    // let contents = std::fs::read_to_string("./src/temp.html").unwrap();
-    let client = build_client(
-        dotenv::var("ADVENT_SESSION_KEY").unwrap().as_str(),
-        "application/x-www-form-urlencoded",
-    )
-    .unwrap();
-    // let client = reqwest::Client::new();
-    let response = client
-        .post(format!("https://adventofcode.com/2022/day/{}/answer", day))
-        .body(format!("level={}&answer={}", level, answer))
-        .header(
-            COOKIE,
-            format!("session={}", dotenv::var("ADVENT_SESSION_KEY").unwrap()),
-        )
-        .header(
-            USER_AGENT,
-            String::from("User-Agent: github.com/rboortman/advent-2022 by ron@techforce1.nl"),
-        )
-        .send()
-        .await
-        .unwrap();
-
-    // println!("{:?}", response.headers());
-    if response.status().as_u16() == 302 {
-        println!("Answer was already submitted!");
-        std::process::exit(0);
+    let title = page
+        .as_deref()
+        .and_then(scaffold::title)
+        .unwrap_or_else(|| format!("Day {}", day));
+    let example = match page
+        .as_deref()
+        .map(|page| client.store_example(year, day, page))
+    {
+        Some(Ok(example)) => Some(example),
+        Some(Err(err)) => {
+            eprintln!("Not filling in the example: {}", err);
+            None
+        }
+        None => None,
+    };
+
+    let module = scaffold::create(&source_dir()?, year, day, &title, example.as_ref())?;
+
+    if let Err(err) = client.get_input(year, day) {
+        eprintln!("Could not download the input: {}", err);
     }
 
-    let contents = response.text().await.unwrap();
+    Ok(module)
+}
 
-    let document = scraper::Html::parse_document(contents.as_str());
-    let selector = scraper::Selector::parse("article").unwrap();
+pub trait Assignment {
+    type Input;
+    type Output: Display;
+    /// Constants of the puzzle that differ between the examples and the real input,
+    /// `()` when there are none
+    type Params: Params;
+
+    fn parse_input(&self, input: &str) -> Result<Self::Input, SolveError>;
+
+    fn silver(
+        &self,
+        input: &Self::Input,
+        params: &Self::Params,
+    ) -> Result<Self::Output, SolveError>;
+    fn gold(&self, input: &Self::Input, params: &Self::Params) -> Result<Self::Output, SolveError>;
+
+    fn timed_silver(
+        &self,
+        input: &Self::Input,
+        params: &Self::Params,
+    ) -> Result<(Self::Output, u128), SolveError> {
+        let before = Instant::now();
+        let solution = self.silver(input, params)?;
+
+        Ok((solution, before.elapsed().as_nanos()))
+    }
+
+    fn timed_gold(
+        &self,
+        input: &Self::Input,
+        params: &Self::Params,
+    ) -> Result<(Self::Output, u128), SolveError> {
+        let before = Instant::now();
+        let solution = self.gold(input, params)?;
+
+        Ok((solution, before.elapsed().as_nanos()))
+    }
 
-    let article = document.select(&selector).next().unwrap();
+    fn timed_parse_input(&self, input: &str) -> Result<(Self::Input, u128), SolveError> {
+        let before = Instant::now();
+        let parsed = self.parse_input(input)?;
 
-    let response = article
-        .text()
-        .collect::<String>()
-        .lines()
-        .filter(|l| !l.trim().starts_with('['))
-        .fold(String::from(""), |acc, line| acc + line.trim())
-        .replace("You guessed", "You guessed: ");
+        Ok((parsed, before.elapsed().as_nanos()))
+    }
 
-    println!("{}", response);
+    /// Parse the input once and solve both parts with it, timing every phase
+    fn run(
+        &self,
+        input: String,
+        params: &Self::Params,
+    ) -> Result<RunResult<Self::Output>, SolveError> {
+        let (parsed, parse_time) = self.timed_parse_input(&input)?;
+        let (silver, silver_time) = self
+            .timed_silver(&parsed, params)
+            .map_err(|err| err.in_part("silver"))?;
+        let (gold, gold_time) = self
+            .timed_gold(&parsed, params)
+            .map_err(|err| err.in_part("gold"))?;
+
+        Ok(RunResult {
+            silver,
+            gold,
+            parse_time,
+            silver_time,
+            gold_time,
+        })
+    }
+}
+
+/// Read the session cookie from the `ADVENT_SESSION_KEY` environment variable
+pub fn session_key() -> Result<String, AdventError> {
+    dotenv::var("ADVENT_SESSION_KEY")
+        .ok()
+        .filter(|key| !key.trim().is_empty())
+        .ok_or(AdventError::MissingSession)
+}
+
+/// The `src` directory of the crate, where the modules of the days live
+pub fn source_dir() -> Result<PathBuf, AdventError> {
+    Ok(project_root::get_project_root()?.join("src"))
+}
+
+/// The directory where inputs and other local state are kept
+pub fn data_dir() -> Result<PathBuf, AdventError> {
+    let data_location = source_dir()?.join("data");
+
+    std::fs::create_dir_all(&data_location)?;
+    Ok(data_location)
+}
+
+/// Read the puzzle input of a day from the cache, downloading it when it is missing or corrupted
+pub fn get_input(year: u16, day: u8) -> Result<String, AdventError> {
+    AocClient::from_env()?.get_input(year, day)
+}
+
+/// Submit an answer, unless the submission history already knows it can't be right
+pub fn send_answer(
+    year: u16,
+    day: u8,
+    level: u8,
+    answer: impl Display,
+) -> Result<SubmissionResult, AdventError> {
+    AocClient::from_env()?.send_answer(year, day, level, answer)
 }
 
 macro_rules! impl_output_from {
     ( $( ($e:tt, $t:ty) ),* ) => {
-        #[derive(Debug, Eq)]
+        #[derive(Debug, Clone, Eq)]
         pub enum Output {
             $( $e($t), )*
         }
@@ -266,7 +623,8 @@ impl_output_from! {
     (I64,    i64),
     (I128,   i128),
     (String, String),
-    (Char,   char)
+    (Char,   char),
+    (Letters, ocr::Letters)
 }
 
 impl Display for Output {
@@ -284,6 +642,17 @@ impl Display for Output {
             Output::I128(v) => write!(f, "{v}"),
             Output::String(v) => write!(f, "{v}"),
             Output::Char(v) => write!(f, "{v}"),
+            Output::Letters(v) => write!(f, "{v}"),
+        }
+    }
+}
+
+impl Output {
+    /// The picture behind an answer that is read from letters, to show next to the text
+    pub fn picture(&self) -> Option<&str> {
+        match self {
+            Output::Letters(letters) => Some(letters.picture()),
+            _ => None,
         }
     }
 }
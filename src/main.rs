@@ -1,16 +1,11 @@
-use dotenv;
-
-use advent_2022::solve;
+use advent_2022::cli::{self, Cli};
+use clap::Parser;
 
 fn main() {
     dotenv::dotenv().ok();
 
-    let mut arguments = std::env::args().skip(1);
-    let assignment: u8 = arguments
-        .next()
-        .expect("No 'assignment' input found")
-        .parse()
-        .expect("No assignment number given!");
-
-    solve(assignment);
+    if let Err(err) = cli::run(Cli::parse()) {
+        eprintln!("{}", err);
+        std::process::exit(1);
+    }
 }
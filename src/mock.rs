@@ -0,0 +1,308 @@
+//! A small stand-in for adventofcode.com, so the client can be used and tested offline.
+//!
+//! It serves inputs, puzzle pages and accepts answers with the same status codes
+//! and messages as the real site, including the wait after a wrong answer.
+
+use std::{
+    collections::HashMap,
+    io,
+    net::TcpListener as StdTcpListener,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::{TcpListener, TcpStream},
+};
+
+#[derive(Debug, Clone)]
+pub struct MockPuzzle {
+    pub input: String,
+    pub answers: [String; 2],
+}
+
+#[derive(Debug, Default)]
+struct MockState {
+    /// How many levels of each day have been solved
+    solved: HashMap<u8, usize>,
+    /// Answers are refused until this moment after a wrong answer
+    locked_until: Option<Instant>,
+}
+
+#[derive(Debug)]
+pub struct MockServer {
+    session: String,
+    puzzles: HashMap<u8, MockPuzzle>,
+    penalty: Duration,
+    state: Mutex<MockState>,
+}
+
+struct Request {
+    method: String,
+    path: String,
+    cookie: Option<String>,
+    body: String,
+}
+
+impl MockServer {
+    /// A server that only accepts requests with the given session cookie
+    pub fn new(session: &str) -> MockServer {
+        MockServer {
+            session: session.to_owned(),
+            puzzles: HashMap::new(),
+            penalty: Duration::from_secs(60),
+            state: Mutex::new(MockState::default()),
+        }
+    }
+
+    /// Unlock a day with its input and the silver and gold answers
+    pub fn with_puzzle(mut self, day: u8, input: &str, silver: &str, gold: &str) -> MockServer {
+        self.puzzles.insert(
+            day,
+            MockPuzzle {
+                input: input.to_owned(),
+                answers: [silver.to_owned(), gold.to_owned()],
+            },
+        );
+        self
+    }
+
+    /// How long answers are refused after a wrong answer
+    pub fn with_penalty(mut self, penalty: Duration) -> MockServer {
+        self.penalty = penalty;
+        self
+    }
+
+    /// Serve on a free local port from a background thread and return the base URL
+    pub fn start(self) -> io::Result<String> {
+        let listener = StdTcpListener::bind("127.0.0.1:0")?;
+        let base_url = format!("http://{}", listener.local_addr()?);
+        let server = Arc::new(self);
+
+        std::thread::spawn(move || {
+            tokio::runtime::Builder::new_current_thread()
+                .enable_all()
+                .build()
+                .expect("Could not start the mock runtime")
+                .block_on(server.serve(listener))
+        });
+
+        Ok(base_url)
+    }
+
+    pub async fn serve(self: Arc<Self>, listener: StdTcpListener) -> io::Result<()> {
+        listener.set_nonblocking(true)?;
+        let listener = TcpListener::from_std(listener)?;
+
+        loop {
+            let (stream, _) = listener.accept().await?;
+            let server = self.clone();
+            tokio::spawn(async move {
+                let _ = server.handle(stream).await;
+            });
+        }
+    }
+
+    async fn handle(&self, mut stream: TcpStream) -> io::Result<()> {
+        let request = read_request(&mut stream).await?;
+        let (status, body) = self.respond(&request);
+        let content_type = if body.starts_with("<!DOCTYPE") {
+            "text/html"
+        } else {
+            "text/plain"
+        };
+
+        let response = format!(
+            "HTTP/1.1 {} {}\r\nContent-Type: {}; charset=utf-8\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            status,
+            reason(status),
+            content_type,
+            body.len(),
+            body
+        );
+        stream.write_all(response.as_bytes()).await?;
+        stream.shutdown().await
+    }
+
+    fn respond(&self, request: &Request) -> (u16, String) {
+        let segments = request
+            .path
+            .trim_matches('/')
+            .split('/')
+            .collect::<Vec<&str>>();
+
+        let (day, action) = match segments[..] {
+            [_, "day", day] => (day.parse::<u8>().ok(), ""),
+            [_, "day", day, action] => (day.parse::<u8>().ok(), action),
+            _ => return (404, String::from("404 Not Found")),
+        };
+
+        let logged_in = request.cookie.as_deref() == Some(&format!("session={}", self.session));
+        let puzzle = match day.and_then(|day| self.puzzles.get(&day)) {
+            Some(puzzle) => puzzle,
+            None => return (404, String::from("Please don't repeatedly request this endpoint before it unlocks! The calendar countdown is synchronized with the server time; the link will be enabled on the calendar the instant this puzzle becomes available.\n")),
+        };
+        let day = day.unwrap_or_default();
+
+        match (request.method.as_str(), action) {
+            // Answers of a session that isn't accepted are sent back to the puzzle page
+            ("POST", "answer") if !logged_in => (302, String::new()),
+            (_, "input" | "answer") if !logged_in => (
+                400,
+                String::from(
+                    "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n",
+                ),
+            ),
+            ("GET", "input") => (200, puzzle.input.clone()),
+            ("GET", "") => (200, self.puzzle_page(day, puzzle, logged_in)),
+            ("POST", "answer") => (200, self.answer(day, puzzle, &request.body)),
+            _ => (404, String::from("404 Not Found")),
+        }
+    }
+
+    fn puzzle_page(&self, day: u8, puzzle: &MockPuzzle, logged_in: bool) -> String {
+        let solved = if logged_in {
+            *self.state.lock().unwrap().solved.get(&day).unwrap_or(&0)
+        } else {
+            0
+        };
+
+        // The input doubles as the example, so its answers are the example answers
+        let mut main = format!(
+            "<article class=\"day-desc\"><h2>--- Day {}: Mock Puzzle ---</h2>\n<p>For example, suppose you have the following list:</p>\n<pre><code>{}</code></pre>\n<p>In this example, the answer is <code><em>{}</em></code>.</p>\n</article>\n",
+            day, puzzle.input, puzzle.answers[0]
+        );
+        for (level, answer) in puzzle.answers.iter().enumerate().take(solved) {
+            main.push_str(&format!(
+                "<p>Your puzzle answer was <code>{}</code>.</p>\n",
+                answer
+            ));
+            if level == 0 {
+                main.push_str(&format!(
+                    "<article class=\"day-desc\"><h2 id=\"part2\">--- Part Two ---</h2>\n<p>Now the answer of the example is <code><em>{}</em></code>.</p>\n</article>\n",
+                    puzzle.answers[1]
+                ));
+            }
+        }
+
+        page(day, &main)
+    }
+
+    fn answer(&self, day: u8, puzzle: &MockPuzzle, body: &str) -> String {
+        let form = parse_form(body);
+        let level = form
+            .get("level")
+            .and_then(|level| level.parse::<usize>().ok())
+            .unwrap_or_default();
+        let answer = form.get("answer").cloned().unwrap_or_default();
+
+        let mut state = self.state.lock().unwrap();
+        let now = Instant::now();
+        let back = format!(" <a href=\"/2022/day/{0}\">[Return to Day {0}]</a>", day);
+
+        if let Some(wait) = state
+            .locked_until
+            .and_then(|until| until.checked_duration_since(now))
+        {
+            let seconds = wait.as_secs() + 1;
+            let wait = if seconds >= 60 {
+                format!("{}m {}s", seconds / 60, seconds % 60)
+            } else {
+                format!("{}s", seconds)
+            };
+            return page(day, &format!("<article><p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have {} left to wait.{}</p></article>", wait, back));
+        }
+
+        let solved = state.solved.entry(day).or_default();
+        if level != *solved + 1 || level > 2 {
+            return page(day, &format!("<article><p>You don't seem to be solving the right level.  Did you already complete it?{}</p></article>", back));
+        }
+
+        let expected = &puzzle.answers[level - 1];
+        if answer == *expected {
+            *solved += 1;
+            return page(day, &format!("<article><p>That's the right answer!  You are one gold star closer to collecting enough star fruit. <a href=\"/2022/day/{}#part2\">[Continue to Part Two]</a></p></article>", day));
+        }
+
+        state.locked_until = Some(now + self.penalty);
+        let hint = match (answer.parse::<i128>(), expected.parse::<i128>()) {
+            (Ok(given), Ok(expected)) if given > expected => "; your answer is too high",
+            (Ok(given), Ok(expected)) if given < expected => "; your answer is too low",
+            _ => ".",
+        };
+        page(day, &format!("<article><p>That's not the right answer{}.  If you're stuck, make sure you're using the full input data.  Please wait one minute before trying again. (You guessed <span style=\"white-space:nowrap;\"><code>{}</code>.)</span>{}</p></article>", hint.trim_end_matches('.'), answer, back))
+    }
+}
+
+fn page(day: u8, main: &str) -> String {
+    format!(
+        "<!DOCTYPE html>\n<html lang=\"en-us\">\n<head>\n<meta charset=\"utf-8\"/>\n<title>Day {} - Advent of Code 2022</title>\n</head>\n<body>\n<header><div><h1 class=\"title-global\"><a href=\"/\">Advent of Code</a></h1></div></header>\n<main>\n{}</main>\n</body>\n</html>\n",
+        day, main
+    )
+}
+
+fn reason(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        302 => "Found",
+        400 => "Bad Request",
+        404 => "Not Found",
+        _ => "Unknown",
+    }
+}
+
+async fn read_request(stream: &mut TcpStream) -> io::Result<Request> {
+    let mut raw = Vec::new();
+    let mut buffer = [0; 1024];
+
+    let header_end = loop {
+        let read = stream.read(&mut buffer).await?;
+        if read == 0 {
+            return Err(io::ErrorKind::UnexpectedEof.into());
+        }
+        raw.extend_from_slice(&buffer[..read]);
+        if let Some(end) = raw.windows(4).position(|window| window == b"\r\n\r\n") {
+            break end + 4;
+        }
+    };
+
+    let head = String::from_utf8_lossy(&raw[..header_end]).to_string();
+    let mut lines = head.lines();
+    let mut request_line = lines.next().unwrap_or_default().split(' ');
+    let method = request_line.next().unwrap_or_default().to_owned();
+    let path = request_line.next().unwrap_or_default().to_owned();
+
+    let mut cookie = None;
+    let mut content_length = 0;
+    for line in lines {
+        if let Some((name, value)) = line.split_once(':') {
+            match name.trim().to_ascii_lowercase().as_str() {
+                "cookie" => cookie = Some(value.trim().to_owned()),
+                "content-length" => content_length = value.trim().parse().unwrap_or(0),
+                _ => (),
+            }
+        }
+    }
+
+    while raw.len() < header_end + content_length {
+        let read = stream.read(&mut buffer).await?;
+        if read == 0 {
+            break;
+        }
+        raw.extend_from_slice(&buffer[..read]);
+    }
+
+    Ok(Request {
+        method,
+        path,
+        cookie,
+        body: String::from_utf8_lossy(&raw[header_end..]).to_string(),
+    })
+}
+
+fn parse_form(body: &str) -> HashMap<String, String> {
+    form_urlencoded::parse(body.as_bytes())
+        .into_owned()
+        .collect()
+}
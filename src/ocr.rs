@@ -0,0 +1,168 @@
+//! Read the capital letters that some puzzles draw on a screen instead of giving an answer,
+//! like the CRT of 2022 day 10.
+
+use std::{
+    fmt::{Display, Formatter, Result as DisplayResult},
+    ops::Range,
+};
+
+/// The letters of the 4×6 font, in the order they are drawn in [`SMALL_FONT`]
+const SMALL_LETTERS: &str = "ABCEFGHIJKLOPRSUYZ";
+const SMALL_FONT: [&str; 6] = [
+    ".##..###...##..####.####..##..#..#.###...##.#..#.#.....##..###..###...###.#..#.#...#.####",
+    "#..#.#..#.#..#.#....#....#..#.#..#..#.....#.#.#..#....#..#.#..#.#..#.#....#..#.#...#....#",
+    "#..#.###..#....###..###..#....####..#.....#.##...#....#..#.#..#.#..#.#....#..#..#.#....#.",
+    "####.#..#.#....#....#....#.##.#..#..#.....#.#.#..#....#..#.###..###...##..#..#...#....#..",
+    "#..#.#..#.#..#.#....#....#..#.#..#..#..#..#.#.#..#....#..#.#....#.#.....#.#..#...#...#...",
+    "#..#.###...##..####.#.....###.#..#.###..##..#..#.####..##..#....#..#.###...##....#...####",
+];
+
+/// The letters of the 6×10 font, in the order they are drawn in [`LARGE_FONT`]
+const LARGE_LETTERS: &str = "ABCEFGHJKLNPRXZ";
+const LARGE_FONT: [&str; 10] = [
+    "..##....#####....####...######..######...####...#....#.....###..#....#..#.......#....#..#####...#####...#....#..######",
+    ".#..#...#....#..#....#..#.......#.......#....#..#....#......#...#...#...#.......##...#..#....#..#....#..#....#.......#",
+    "#....#..#....#..#.......#.......#.......#.......#....#......#...#..#....#.......##...#..#....#..#....#...#..#........#",
+    "#....#..#....#..#.......#.......#.......#.......#....#......#...#.#.....#.......#.#..#..#....#..#....#...#..#.......#.",
+    "#....#..#####...#.......#####...#####...#.......######......#...##......#.......#.#..#..#####...#####.....##.......#..",
+    "######..#....#..#.......#.......#.......#..###..#....#......#...##......#.......#..#.#..#.......#..#......##......#...",
+    "#....#..#....#..#.......#.......#.......#....#..#....#......#...#.#.....#.......#..#.#..#.......#...#....#..#....#....",
+    "#....#..#....#..#.......#.......#.......#....#..#....#..#...#...#..#....#.......#...##..#.......#...#....#..#...#.....",
+    "#....#..#....#..#....#..#.......#.......#...##..#....#..#...#...#...#...#.......#...##..#.......#....#..#....#..#.....",
+    "#....#..#####....####...######..#........###.#..#....#...###....#....#..######..#....#..#.......#....#..#....#..######",
+];
+
+/// A picture of letters, which shows as the picture until its text is known
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Letters {
+    picture: String,
+    text: Option<String>,
+}
+
+impl Letters {
+    pub fn new(picture: String) -> Letters {
+        let text = decode(&picture);
+        Letters { picture, text }
+    }
+
+    pub fn picture(&self) -> &str {
+        &self.picture
+    }
+
+    /// The letters in the picture, if every one of them is recognized
+    pub fn text(&self) -> Option<&str> {
+        self.text.as_deref()
+    }
+}
+
+/// The decoded text when there is one, as that is what Advent of Code expects as the answer
+impl Display for Letters {
+    fn fmt(&self, f: &mut Formatter<'_>) -> DisplayResult {
+        write!(f, "{}", self.text.as_ref().unwrap_or(&self.picture))
+    }
+}
+
+/// Read the letters of a picture drawn with `#` or `█`, in either font. Every letter has to be
+/// recognized, otherwise there is no text at all.
+pub fn decode(picture: &str) -> Option<String> {
+    let rows = pixels(picture.lines());
+    let glyphs = match rows.len() {
+        6 => glyphs(&SMALL_FONT, SMALL_LETTERS),
+        10 => glyphs(&LARGE_FONT, LARGE_LETTERS),
+        _ => return None,
+    };
+
+    let text = columns(&rows)
+        .into_iter()
+        .map(|columns| {
+            let pattern = pattern(&rows, columns);
+            glyphs
+                .iter()
+                .find(|(_, glyph)| *glyph == pattern)
+                .map(|(letter, _)| *letter)
+        })
+        .collect::<Option<String>>()?;
+
+    (!text.is_empty()).then_some(text)
+}
+
+fn pixels<'a>(lines: impl Iterator<Item = &'a str>) -> Vec<Vec<bool>> {
+    lines
+        .map(|line| line.chars().map(|c| c == '#' || c == '█').collect())
+        .collect()
+}
+
+/// The columns of every letter: runs of columns with at least one lit pixel
+fn columns(rows: &[Vec<bool>]) -> Vec<Range<usize>> {
+    let width = rows.iter().map(Vec::len).max().unwrap_or(0);
+    let lit = |x: usize| rows.iter().any(|row| row.get(x) == Some(&true));
+
+    let mut letters = Vec::new();
+    let mut start = None;
+    for x in 0..=width {
+        match (lit(x), start) {
+            (true, None) => start = Some(x),
+            (false, Some(from)) => {
+                letters.push(from..x);
+                start = None;
+            }
+            _ => (),
+        }
+    }
+    letters
+}
+
+/// The pixels of one letter as rows of `#` and `.`, to compare against the fonts
+fn pattern(rows: &[Vec<bool>], columns: Range<usize>) -> String {
+    rows.iter()
+        .map(|row| {
+            columns
+                .clone()
+                .map(|x| match row.get(x) {
+                    Some(true) => '#',
+                    _ => '.',
+                })
+                .collect::<String>()
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+fn glyphs(font: &[&str], letters: &str) -> Vec<(char, String)> {
+    let rows = pixels(font.iter().copied());
+    letters
+        .chars()
+        .zip(columns(&rows))
+        .map(|(letter, columns)| (letter, pattern(&rows, columns)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fonts() {
+        assert_eq!(decode(&SMALL_FONT.join("\n")).unwrap(), SMALL_LETTERS);
+        assert_eq!(decode(&LARGE_FONT.join("\n")).unwrap(), LARGE_LETTERS);
+    }
+
+    #[test]
+    fn test_decode() {
+        let screen = "\
+████.#..#.###..####.###....##..##..#....
+#....#..#.#..#....#.#..#....#.#..#.#....
+###..####.#..#...#..#..#....#.#....#....
+#....#..#.###...#...###.....#.#.##.#....
+#....#..#.#....#....#....#..#.#..#.#....
+####.#..#.#....####.#.....##...###.####.";
+        assert_eq!(decode(screen).as_deref(), Some("EHPZPJGL"));
+
+        let letters = Letters::new(screen.replace('.', " "));
+        assert_eq!(letters.to_string(), "EHPZPJGL");
+
+        let unknown = Letters::new(String::from("██  ██\n██  ██"));
+        assert_eq!(unknown.text(), None);
+        assert_eq!(unknown.to_string(), unknown.picture());
+    }
+}
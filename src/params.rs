@@ -0,0 +1,120 @@
+//! Tunable constants of assignments, like the row that is scanned or the number of rounds.
+//! The defaults fit the real puzzle input, the examples and the CLI can override them.
+
+use crate::error::SolveError;
+
+/// The parameters of an assignment, of which every field can be overridden by name
+pub trait Params: Default {
+    /// Override the parameter called `name` with the textual `value`
+    fn set(&mut self, name: &str, value: &str) -> Result<(), SolveError>;
+
+    /// The defaults with every `(name, value)` of `overrides` applied in order
+    fn with_overrides(overrides: &[(String, String)]) -> Result<Self, SolveError> {
+        let mut params = Self::default();
+        for (name, value) in overrides {
+            params.set(name, value)?;
+        }
+        Ok(params)
+    }
+}
+
+/// Assignments without parameters
+impl Params for () {
+    fn set(&mut self, name: &str, _: &str) -> Result<(), SolveError> {
+        Err(unknown(name, &[]))
+    }
+}
+
+/// An error for a parameter that does not exist, listing the ones that do
+pub fn unknown(name: &str, known: &[&str]) -> SolveError {
+    let error = SolveError::new(format!("Unknown parameter '{}'", name));
+    match known {
+        [] => error.with_hint("this day has no parameters"),
+        known => error.with_hint(format!("the parameters are {}", known.join(", "))),
+    }
+}
+
+/// Parse a `name=value` argument of `--param`
+pub fn parse_override(argument: &str) -> Result<(String, String), String> {
+    match argument.split_once('=') {
+        Some((name, value)) if !name.trim().is_empty() => {
+            Ok((name.trim().to_string(), value.trim().to_string()))
+        }
+        _ => Err(format!("Expected name=value, found '{}'", argument)),
+    }
+}
+
+/// Declare a parameter struct of which every field has a default, together with its
+/// [`Params`] implementation
+macro_rules! params {
+    (
+        $(#[$meta:meta])*
+        pub struct $name:ident {
+            $( $(#[$field_meta:meta])* $field:ident: $ty:ty = $default:expr, )*
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, PartialEq)]
+        pub struct $name {
+            $( $(#[$field_meta])* pub $field: $ty, )*
+        }
+
+        impl Default for $name {
+            fn default() -> Self {
+                $name { $( $field: $default, )* }
+            }
+        }
+
+        impl $crate::params::Params for $name {
+            fn set(&mut self, name: &str, value: &str) -> Result<(), $crate::error::SolveError> {
+                match name {
+                    $(
+                        stringify!($field) => {
+                            self.$field = value.parse().map_err(|err| {
+                                $crate::error::SolveError::new(format!(
+                                    "Invalid value '{}' for parameter {}: {}",
+                                    value, name, err
+                                ))
+                            })?
+                        }
+                    )*
+                    _ => return Err($crate::params::unknown(name, &[$( stringify!($field) ),*])),
+                }
+                Ok(())
+            }
+        }
+    };
+}
+
+pub(crate) use params;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    params! {
+        pub struct TestParams {
+            row: i32 = 2_000_000,
+            rounds: usize = 20,
+        }
+    }
+
+    #[test]
+    fn test_overrides() {
+        let overrides = vec![parse_override("row=10").unwrap()];
+        let params = TestParams::with_overrides(&overrides).unwrap();
+        assert_eq!(
+            params,
+            TestParams {
+                row: 10,
+                rounds: 20
+            }
+        );
+
+        let err = TestParams::default().set("rows", "10").unwrap_err();
+        assert_eq!(err.hint.unwrap(), "the parameters are row, rounds");
+        assert!(TestParams::default().set("row", "ten").is_err());
+        assert!(<()>::with_overrides(&overrides).is_err());
+        assert!(parse_override("row").is_err());
+    }
+}
@@ -0,0 +1,83 @@
+//! Helpers for parsing puzzle input with errors that point at the offending text.
+
+use std::{fmt::Display, str::FromStr};
+
+use crate::error::SolveError;
+
+/// A line of the puzzle input that remembers where it came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Line<'a> {
+    /// Index of the line, starting at 0
+    pub number: usize,
+    pub text: &'a str,
+}
+
+/// The lines of an input together with their line numbers
+pub fn lines(input: &str) -> impl Iterator<Item = Line<'_>> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(number, text)| Line { number, text })
+}
+
+impl<'a> Line<'a> {
+    /// An error about `text`, which should be a part of this line
+    pub fn error(&self, text: &str, message: impl Into<String>) -> SolveError {
+        SolveError::at(self.number, self.text, text, message)
+    }
+
+    /// Split the whole line at the first `delimiter`
+    pub fn split_once(&self, delimiter: &str) -> Result<(&'a str, &'a str), SolveError> {
+        self.split(self.text, delimiter)
+    }
+
+    /// Split `text`, a part of this line, at the first `delimiter`
+    pub fn split(&self, text: &'a str, delimiter: &str) -> Result<(&'a str, &'a str), SolveError> {
+        text.split_once(delimiter)
+            .ok_or_else(|| self.error(text, format!("Expected '{}'", delimiter)))
+    }
+
+    /// The rest of the line after `prefix`
+    pub fn strip_prefix(&self, prefix: &str) -> Result<&'a str, SolveError> {
+        self.text
+            .strip_prefix(prefix)
+            .ok_or_else(|| self.error(self.text, format!("Expected '{}'", prefix)))
+    }
+
+    /// Parse `text`, a part of this line, into a `T`
+    pub fn parse<T>(&self, text: &str) -> Result<T, SolveError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        text.trim()
+            .parse()
+            .map_err(|err| self.error(text, format!("Could not parse '{}': {}", text, err)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_line() {
+        let input = "2-4,6-8\n2-3,x-5";
+        let line = lines(input).nth(1).unwrap();
+        let (first, second) = line.split_once(",").unwrap();
+        let (lower, upper) = line.split(second, "-").unwrap();
+
+        assert_eq!(line.parse::<u32>(first.split('-').next().unwrap()), Ok(2));
+        assert_eq!(line.parse::<u32>(upper), Ok(5));
+
+        let err = line.parse::<u32>(lower).unwrap_err();
+        let location = err.location.unwrap();
+        assert_eq!((location.line, location.column), (2, 5));
+        assert_eq!(location.text, "x");
+
+        let err = line.split(first, ",").unwrap_err();
+        assert_eq!(err.message, "Expected ','");
+        assert_eq!(err.location.unwrap().column, 1);
+        assert_eq!(line.strip_prefix("2-").unwrap(), "3,x-5");
+    }
+}
@@ -0,0 +1,288 @@
+//! Every benchmark run is appended to `src/data/perf.jsonl`, so timings can be
+//! followed over commits and a part that got slower is noticed.
+
+use std::{
+    fs::OpenOptions,
+    io::Write,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    bench::{BenchReport, Regression, Stats},
+    error::AdventError,
+    queue, store,
+    summary::format_time,
+};
+
+/// The benchmark of one part of a day at some commit
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PerfEntry {
+    pub year: u16,
+    pub day: u8,
+    /// `Parse`, `Silver` or `Gold`
+    pub part: String,
+    /// SHA-256 of the input, timings of different inputs are not compared
+    pub input_hash: String,
+    /// The commit that was checked out, if it could be found
+    pub commit: Option<String>,
+    /// Unix timestamp in seconds of the benchmark
+    pub timestamp: u64,
+    pub iterations: u32,
+    pub stats: Stats,
+}
+
+impl PerfEntry {
+    /// One entry for every part of a benchmark report
+    pub fn from_report(report: &BenchReport, input_hash: &str) -> Vec<PerfEntry> {
+        let commit = git_commit();
+        let timestamp = queue::now();
+
+        report
+            .phases()
+            .into_iter()
+            .map(|(part, stats)| PerfEntry {
+                year: report.year,
+                day: report.day,
+                part: part.to_owned(),
+                input_hash: input_hash.to_owned(),
+                commit: commit.clone(),
+                timestamp,
+                iterations: report.iterations,
+                stats: stats.clone(),
+            })
+            .collect()
+    }
+
+    fn same_series(&self, other: &PerfEntry) -> bool {
+        (self.year, self.day, &self.part, &self.input_hash)
+            == (other.year, other.day, &other.part, &other.input_hash)
+    }
+}
+
+/// All benchmarks that were ever run, oldest first
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct PerfHistory {
+    entries: Vec<PerfEntry>,
+}
+
+impl PerfHistory {
+    fn location(data_dir: &Path) -> PathBuf {
+        data_dir.join("perf.jsonl")
+    }
+
+    pub fn load(data_dir: &Path) -> Result<PerfHistory, AdventError> {
+        let contents = match store::read(&PerfHistory::location(data_dir))? {
+            Some(contents) => contents,
+            None => return Ok(PerfHistory::default()),
+        };
+
+        let entries = contents
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(serde_json::from_str)
+            .collect::<Result<_, _>>()?;
+        Ok(PerfHistory { entries })
+    }
+
+    /// Add entries to the history file without rewriting what is already there
+    pub fn append(data_dir: &Path, entries: &[PerfEntry]) -> Result<(), AdventError> {
+        std::fs::create_dir_all(data_dir)?;
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(PerfHistory::location(data_dir))?;
+
+        for entry in entries {
+            writeln!(file, "{}", serde_json::to_string(entry)?)?;
+        }
+        Ok(())
+    }
+
+    pub fn entries(&self) -> &[PerfEntry] {
+        &self.entries
+    }
+
+    /// The median of the medians of the last `window` runs of the same series before `entry`
+    pub fn baseline(&self, entry: &PerfEntry, window: usize) -> Option<u64> {
+        let end = self
+            .entries
+            .iter()
+            .position(|other| other == entry)
+            .unwrap_or(self.entries.len());
+
+        let mut medians = self.entries[..end]
+            .iter()
+            .rev()
+            .filter(|other| other.same_series(entry))
+            .take(window)
+            .map(|other| other.stats.median)
+            .collect::<Vec<_>>();
+        medians.sort_unstable();
+
+        Stats::from_samples(&medians).map(|stats| stats.median)
+    }
+
+    /// Compare `entry` against its rolling baseline and return a regression when it
+    /// got more than `threshold` percent slower
+    pub fn check(&self, entry: &PerfEntry, window: usize, threshold: f64) -> Option<Regression> {
+        let regression = Regression {
+            phase: entry.part.clone(),
+            before: self.baseline(entry, window)?,
+            after: entry.stats.median,
+        };
+
+        (regression.change() > threshold).then_some(regression)
+    }
+
+    /// Print how every part of a day developed, flagging runs that were slower than their baseline
+    pub fn print_trend(&self, year: u16, day: u8, limit: usize, window: usize, threshold: f64) {
+        for part in ["Parse", "Silver", "Gold"] {
+            let series = self
+                .entries
+                .iter()
+                .filter(|entry| entry.year == year && entry.day == day && entry.part == part)
+                .collect::<Vec<_>>();
+            if series.is_empty() {
+                continue;
+            }
+
+            println!("{} of day {}", part, day);
+            for entry in &series[series.len().saturating_sub(limit)..] {
+                let change = match self.baseline(entry, window) {
+                    Some(baseline) => {
+                        let regression = Regression {
+                            phase: entry.part.clone(),
+                            before: baseline,
+                            after: entry.stats.median,
+                        };
+                        let flag = if regression.change() > threshold {
+                            " slower"
+                        } else {
+                            ""
+                        };
+                        format!("{:+.1}%{}", regression.change(), flag)
+                    }
+                    None => String::from("-"),
+                };
+
+                println!(
+                    "| {} | {:<9} | {:<8} | {:>10} | p95 {:>10} | {}",
+                    date(entry.timestamp),
+                    entry.commit.as_deref().unwrap_or("unknown"),
+                    &entry.input_hash[..entry.input_hash.len().min(8)],
+                    format_time(entry.stats.median as u128),
+                    format_time(entry.stats.p95 as u128),
+                    change
+                );
+            }
+        }
+    }
+}
+
+/// The short hash of the checked out commit, marked when there are uncommitted changes
+fn git_commit() -> Option<String> {
+    let git = |args: &[&str]| {
+        Command::new("git")
+            .args(args)
+            .current_dir(env!("CARGO_MANIFEST_DIR"))
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+            .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_owned())
+    };
+
+    let commit = git(&["rev-parse", "--short", "HEAD"])?;
+    match git(&["status", "--porcelain", "--untracked-files=no"]) {
+        Some(changes) if !changes.is_empty() => Some(format!("{}+", commit)),
+        _ => Some(commit),
+    }
+}
+
+/// Format a Unix timestamp as a `YYYY-MM-DD` date in UTC
+fn date(timestamp: u64) -> String {
+    // Civil from days, see http://howardhinnant.github.io/date_algorithms.html
+    let days = (timestamp / 86_400) as i64 + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month + 2) / 5 + 1;
+    let month = if month < 10 { month + 3 } else { month - 9 };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(part: &str, input_hash: &str, median: u64) -> PerfEntry {
+        PerfEntry {
+            year: 2022,
+            day: 13,
+            part: part.to_owned(),
+            input_hash: input_hash.to_owned(),
+            commit: None,
+            timestamp: median,
+            iterations: 10,
+            stats: Stats {
+                min: median,
+                median,
+                mean: median as f64,
+                p95: median,
+                stddev: 0.0,
+            },
+        }
+    }
+
+    #[test]
+    fn test_baseline() {
+        let history = PerfHistory {
+            entries: vec![
+                entry("Silver", "a", 100),
+                entry("Silver", "a", 300),
+                entry("Gold", "a", 1000),
+                entry("Silver", "b", 5000),
+                entry("Silver", "a", 200),
+                entry("Silver", "a", 250),
+            ],
+        };
+
+        let last = &history.entries()[5];
+        assert_eq!(history.baseline(last, 3), Some(200));
+        assert_eq!(history.baseline(last, 1), Some(200));
+        assert_eq!(history.baseline(&history.entries()[0], 3), None);
+        assert_eq!(history.check(last, 3, 30.0), None);
+        assert_eq!(history.check(last, 3, 10.0).unwrap().phase, "Silver");
+
+        // A new entry is compared with everything before it
+        let new = entry("Silver", "a", 400);
+        assert_eq!(history.baseline(&new, 2), Some(225));
+    }
+
+    #[test]
+    fn test_append_and_load() {
+        let data_dir = std::env::temp_dir().join(format!("advent_perf_{}", std::process::id()));
+        let entries = [entry("Parse", "a", 1), entry("Gold", "a", 2)];
+
+        PerfHistory::append(&data_dir, &entries[..1]).unwrap();
+        PerfHistory::append(&data_dir, &entries[1..]).unwrap();
+        let history = PerfHistory::load(&data_dir).unwrap();
+        std::fs::remove_dir_all(&data_dir).unwrap();
+
+        assert_eq!(history.entries(), &entries[..]);
+    }
+
+    #[test]
+    fn test_date() {
+        assert_eq!(date(0), "1970-01-01");
+        assert_eq!(date(1_669_852_800), "2022-12-01");
+        assert_eq!(date(1_709_164_800), "2024-02-29");
+    }
+}
@@ -0,0 +1,144 @@
+use std::{
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{error::AdventError, store};
+
+/// An answer that could not be submitted yet because of a rate limit
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct QueuedAnswer {
+    #[serde(default = "default_year")]
+    pub year: u16,
+    pub day: u8,
+    pub level: u8,
+    pub answer: String,
+    /// Unix timestamp in seconds after which the answer may be submitted
+    pub not_before: u64,
+}
+
+impl QueuedAnswer {
+    pub fn new(year: u16, day: u8, level: u8, answer: String, wait: Duration) -> QueuedAnswer {
+        QueuedAnswer {
+            year,
+            day,
+            level,
+            answer,
+            not_before: now() + wait.as_secs(),
+        }
+    }
+
+    /// How long until the answer may be submitted
+    pub fn remaining(&self) -> Duration {
+        Duration::from_secs(self.not_before.saturating_sub(now()))
+    }
+}
+
+/// Answers waiting to be submitted, stored in `src/data/queue.json`
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SubmissionQueue {
+    entries: Vec<QueuedAnswer>,
+}
+
+impl SubmissionQueue {
+    fn location(data_dir: &Path) -> PathBuf {
+        data_dir.join("queue.json")
+    }
+
+    pub fn load(data_dir: &Path) -> Result<SubmissionQueue, AdventError> {
+        store::load(&SubmissionQueue::location(data_dir))
+    }
+
+    pub fn save(&self, data_dir: &Path) -> Result<(), AdventError> {
+        store::save(&SubmissionQueue::location(data_dir), self)
+    }
+
+    pub fn entries(&self) -> &[QueuedAnswer] {
+        &self.entries
+    }
+
+    /// Queue an answer, replacing an earlier answer for the same level
+    pub fn push(&mut self, queued: QueuedAnswer) {
+        self.entries.retain(|entry| {
+            (entry.year, entry.day, entry.level) != (queued.year, queued.day, queued.level)
+        });
+        self.entries.push(queued);
+    }
+
+    /// Remove and return every answer that may be submitted by now
+    pub fn take_due(&mut self) -> Vec<QueuedAnswer> {
+        let (due, waiting) = self
+            .entries
+            .drain(..)
+            .partition(|entry| entry.remaining().is_zero());
+        self.entries = waiting;
+        due
+    }
+}
+
+/// Answers queued before years were tracked were all for 2022
+fn default_year() -> u16 {
+    2022
+}
+
+/// Seconds since the Unix epoch
+pub(crate) fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_take_due() {
+        let mut queue = SubmissionQueue::default();
+        queue.push(QueuedAnswer::new(
+            2022,
+            1,
+            1,
+            String::from("1"),
+            Duration::ZERO,
+        ));
+        queue.push(QueuedAnswer::new(
+            2022,
+            2,
+            1,
+            String::from("2"),
+            Duration::from_secs(60),
+        ));
+
+        let due = queue.take_due();
+        assert_eq!(due.len(), 1);
+        assert_eq!(due[0].day, 1);
+        assert_eq!(queue.entries().len(), 1);
+        assert_eq!(queue.entries()[0].day, 2);
+    }
+
+    #[test]
+    fn test_push_replaces() {
+        let mut queue = SubmissionQueue::default();
+        queue.push(QueuedAnswer::new(
+            2022,
+            1,
+            1,
+            String::from("1"),
+            Duration::ZERO,
+        ));
+        queue.push(QueuedAnswer::new(
+            2022,
+            1,
+            1,
+            String::from("2"),
+            Duration::ZERO,
+        ));
+
+        assert_eq!(queue.entries().len(), 1);
+        assert_eq!(queue.entries()[0].answer, "2");
+    }
+}
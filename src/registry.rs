@@ -0,0 +1,90 @@
+use crate::{error::SolveError, params::Params, year_2022, Assignment, Output, RunResult};
+
+/// A type-erased [`Assignment`], so days with different inputs can share one table
+pub trait Solver {
+    /// Solve with the default parameters after applying the `(name, value)` overrides
+    fn solve(&self, input: String, overrides: &[(String, String)])
+        -> Result<RunResult, SolveError>;
+}
+
+impl<A: Assignment<Output = Output>> Solver for A {
+    fn solve(
+        &self,
+        input: String,
+        overrides: &[(String, String)],
+    ) -> Result<RunResult, SolveError> {
+        self.run(input, &A::Params::with_overrides(overrides)?)
+    }
+}
+
+/// A solved day with everything needed to run it
+pub struct Day {
+    pub year: u16,
+    pub day: u8,
+    pub title: &'static str,
+    pub solver: fn() -> Box<dyn Solver>,
+}
+
+impl Day {
+    pub fn solve(&self, input: String) -> Result<RunResult, SolveError> {
+        self.solve_with(input, &[])
+    }
+
+    /// Solve with some parameters overridden by `(name, value)` pairs
+    pub fn solve_with(
+        &self,
+        input: String,
+        overrides: &[(String, String)],
+    ) -> Result<RunResult, SolveError> {
+        (self.solver)().solve(input, overrides)
+    }
+}
+
+/// Declare the assignment modules of a year together with the `DAYS` table listing them
+macro_rules! register_days {
+    ( $year:literal; $( $day:literal => $module:ident, $title:literal; )* ) => {
+        $( mod $module; )*
+
+        pub static DAYS: &[$crate::registry::Day] = &[
+            $(
+                $crate::registry::Day {
+                    year: $year,
+                    day: $day,
+                    title: $title,
+                    solver: || Box::new($module::Solution::new()),
+                },
+            )*
+        ];
+    };
+}
+
+pub(crate) use register_days;
+
+/// Every registered day, ordered by year and day
+pub fn days() -> impl Iterator<Item = &'static Day> {
+    year_2022::DAYS.iter()
+}
+
+pub fn days_of(year: u16) -> impl Iterator<Item = &'static Day> {
+    days().filter(move |day| day.year == year)
+}
+
+pub fn find(year: u16, day: u8) -> Option<&'static Day> {
+    days().find(|d| d.year == year && d.day == day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_days_are_ordered() {
+        let keys = days().map(|d| (d.year, d.day)).collect::<Vec<_>>();
+        let mut sorted = keys.clone();
+        sorted.sort();
+        sorted.dedup();
+
+        assert_eq!(keys, sorted);
+        assert!(days().all(|d| (1..=25).contains(&d.day) && !d.title.is_empty()));
+    }
+}
@@ -0,0 +1,426 @@
+//! Reporters turn the results of running days into something to read: a table for
+//! the terminal, JSON lines for scripts, Markdown for the README and JUnit XML for CI.
+
+use std::{
+    fs::File,
+    io::{self, Write},
+    path::Path,
+};
+
+use clap::ValueEnum;
+use serde::Serialize;
+
+use crate::{
+    answers::Verdict,
+    error::AdventError,
+    summary::{self, format_answer, format_time, DayReport},
+};
+
+/// Receives the result of every day that is run
+pub trait Reporter {
+    /// Called for every day as soon as it has been run
+    fn day(&mut self, report: DayReport) -> io::Result<()>;
+    /// Called once after the last day, to write totals or close the document
+    fn finish(&mut self) -> io::Result<()>;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ReportFormat {
+    /// A table for humans
+    Terminal,
+    /// A JSON object per day
+    Json,
+    /// A Markdown table
+    Markdown,
+    /// A JUnit XML test suite with a test case per day
+    Junit,
+}
+
+/// A reporter writing `format` to `path`, or to stdout without a path
+pub fn reporter(
+    format: ReportFormat,
+    path: Option<&Path>,
+) -> Result<Box<dyn Reporter>, AdventError> {
+    let out: Box<dyn Write> = match path {
+        Some(path) => Box::new(File::create(path)?),
+        None => Box::new(io::stdout()),
+    };
+
+    Ok(match format {
+        ReportFormat::Terminal => Box::new(TerminalReporter::new(out)),
+        ReportFormat::Json => Box::new(JsonReporter::new(out)),
+        ReportFormat::Markdown => Box::new(MarkdownReporter::new(out)),
+        ReportFormat::Junit => Box::new(JunitReporter::new(out)),
+    })
+}
+
+/// Collects every day and prints them as one boxed table with a total
+pub struct TerminalReporter<W> {
+    out: W,
+    reports: Vec<DayReport>,
+}
+
+impl<W: Write> TerminalReporter<W> {
+    pub fn new(out: W) -> TerminalReporter<W> {
+        TerminalReporter {
+            out,
+            reports: Vec::new(),
+        }
+    }
+}
+
+impl<W: Write> Reporter for TerminalReporter<W> {
+    fn day(&mut self, report: DayReport) -> io::Result<()> {
+        self.reports.push(report);
+        Ok(())
+    }
+
+    fn finish(&mut self) -> io::Result<()> {
+        write!(self.out, "{}", summary::table(&self.reports))?;
+
+        // The table only has room for the letters, so show what they were read from below it
+        for report in &self.reports {
+            let result = match &report.result {
+                Ok(result) => result,
+                Err(_) => continue,
+            };
+            for (part, answer) in [("silver", &result.silver), ("gold", &result.gold)] {
+                if let Some(picture) = answer.picture() {
+                    write!(self.out, "\nDay {} {}:\n{}\n", report.day, part, picture)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct JsonDay<'a> {
+    year: u16,
+    day: u8,
+    title: &'a str,
+    silver: Option<String>,
+    gold: Option<String>,
+    parse_ns: Option<u128>,
+    silver_ns: Option<u128>,
+    gold_ns: Option<u128>,
+    status: String,
+    passed: bool,
+}
+
+/// Writes a JSON object per line as soon as a day is done
+pub struct JsonReporter<W> {
+    out: W,
+}
+
+impl<W: Write> JsonReporter<W> {
+    pub fn new(out: W) -> JsonReporter<W> {
+        JsonReporter { out }
+    }
+}
+
+impl<W: Write> Reporter for JsonReporter<W> {
+    fn day(&mut self, report: DayReport) -> io::Result<()> {
+        let result = report.result.as_ref().ok();
+        let line = JsonDay {
+            year: report.year,
+            day: report.day,
+            title: report.title,
+            silver: result.map(|result| result.silver.to_string()),
+            gold: result.map(|result| result.gold.to_string()),
+            parse_ns: result.map(|result| result.parse_time),
+            silver_ns: result.map(|result| result.silver_time),
+            gold_ns: result.map(|result| result.gold_time),
+            status: report.status(),
+            passed: !report.is_failure(),
+        };
+
+        writeln!(self.out, "{}", serde_json::to_string(&line)?)
+    }
+
+    fn finish(&mut self) -> io::Result<()> {
+        self.out.flush()
+    }
+}
+
+/// Collects every day and prints them as a Markdown table, like the one in the README
+pub struct MarkdownReporter<W> {
+    out: W,
+    reports: Vec<DayReport>,
+}
+
+impl<W: Write> MarkdownReporter<W> {
+    pub fn new(out: W) -> MarkdownReporter<W> {
+        MarkdownReporter {
+            out,
+            reports: Vec::new(),
+        }
+    }
+}
+
+impl<W: Write> Reporter for MarkdownReporter<W> {
+    fn day(&mut self, report: DayReport) -> io::Result<()> {
+        self.reports.push(report);
+        Ok(())
+    }
+
+    fn finish(&mut self) -> io::Result<()> {
+        writeln!(
+            self.out,
+            "| Day | Title | Silver | Gold | Parse | Silver time | Gold time | Status |"
+        )?;
+        writeln!(self.out, "|---:|---|---|---|---:|---:|---:|---|")?;
+
+        for report in &self.reports {
+            let cells = match &report.result {
+                Ok(result) => [
+                    format!("`{}`", cell(&format_answer(&result.silver.to_string()))),
+                    format!("`{}`", cell(&format_answer(&result.gold.to_string()))),
+                    format_time(result.parse_time),
+                    format_time(result.silver_time),
+                    format_time(result.gold_time),
+                ],
+                Err(_) => Default::default(),
+            };
+
+            writeln!(
+                self.out,
+                "| {} | {} | {} | {} |",
+                report.day,
+                cell(report.title),
+                cells.join(" | "),
+                cell(&report.status())
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Collects every day and prints them as a JUnit test suite, so CI can show which days pass
+pub struct JunitReporter<W> {
+    out: W,
+    reports: Vec<DayReport>,
+}
+
+impl<W: Write> JunitReporter<W> {
+    pub fn new(out: W) -> JunitReporter<W> {
+        JunitReporter {
+            out,
+            reports: Vec::new(),
+        }
+    }
+}
+
+impl<W: Write> Reporter for JunitReporter<W> {
+    fn day(&mut self, report: DayReport) -> io::Result<()> {
+        self.reports.push(report);
+        Ok(())
+    }
+
+    fn finish(&mut self) -> io::Result<()> {
+        let seconds = |report: &DayReport| {
+            report
+                .result
+                .as_ref()
+                .map_or(0.0, |result| result.total_time() as f64 / 1e9)
+        };
+        let count = |matches: fn(&DayReport) -> bool| {
+            self.reports.iter().filter(|report| matches(report)).count()
+        };
+        let year = self.reports.first().map_or(0, |report| report.year);
+
+        writeln!(self.out, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+        writeln!(
+            self.out,
+            r#"<testsuite name="advent_{}" tests="{}" failures="{}" errors="{}" skipped="{}" time="{:.6}">"#,
+            year,
+            self.reports.len(),
+            count(|report| matches!(report.verdict, Some(Verdict::Mismatch(_)))),
+            count(|report| report.is_failure()
+                && !matches!(report.verdict, Some(Verdict::Mismatch(_)))),
+            count(|report| matches!(report.verdict, Some(Verdict::Skipped(_)))),
+            self.reports.iter().map(seconds).sum::<f64>(),
+        )?;
+
+        for report in &self.reports {
+            write!(
+                self.out,
+                r#"  <testcase classname="year_{}" name="day_{:02} {}" time="{:.6}""#,
+                report.year,
+                report.day,
+                escape(report.title),
+                seconds(report)
+            )?;
+
+            let status = escape(&report.status());
+            match &report.verdict {
+                Some(Verdict::Mismatch(_)) => writeln!(
+                    self.out,
+                    ">\n    <failure message=\"{}\"/>\n  </testcase>",
+                    status
+                )?,
+                Some(Verdict::Skipped(_)) => writeln!(
+                    self.out,
+                    ">\n    <skipped message=\"{}\"/>\n  </testcase>",
+                    status
+                )?,
+                _ if report.is_failure() => writeln!(
+                    self.out,
+                    ">\n    <error message=\"{}\"/>\n  </testcase>",
+                    status
+                )?,
+                _ => writeln!(self.out, "/>")?,
+            }
+        }
+
+        writeln!(self.out, "</testsuite>")
+    }
+}
+
+/// A pipe would end a Markdown table cell early, even inside a code span
+fn cell(text: &str) -> String {
+    text.replace('|', "\\|")
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ocr::Letters, Output, RunResult};
+
+    fn reports() -> Vec<DayReport> {
+        let result = |silver: u32| RunResult {
+            silver: Output::U32(silver),
+            gold: Output::U32(45000),
+            parse_time: 1_000,
+            silver_time: 2_000,
+            gold_time: 3_000,
+        };
+        let report = |day: u8, result: Result<RunResult, String>, verdict: Verdict| DayReport {
+            year: 2022,
+            day,
+            title: "Calorie <Counting>",
+            result,
+            verdict: Some(verdict),
+        };
+
+        vec![
+            report(1, Ok(result(24000)), Verdict::Passed),
+            report(
+                2,
+                Ok(result(1)),
+                Verdict::Mismatch(vec![("silver", String::from("15"), String::from("1"))]),
+            ),
+            report(
+                3,
+                Err(String::from("Unknown symbol")),
+                Verdict::Failed(String::from("Unknown symbol")),
+            ),
+            report(
+                4,
+                Err(String::from("no cached input")),
+                Verdict::Skipped(String::from("no cached input")),
+            ),
+        ]
+    }
+
+    fn render(mut reporter: impl Reporter) {
+        for report in reports() {
+            reporter.day(report).unwrap();
+        }
+        reporter.finish().unwrap();
+    }
+
+    #[test]
+    fn test_json() {
+        let mut out = Vec::new();
+        render(JsonReporter::new(&mut out));
+        let lines = String::from_utf8(out).unwrap();
+        let lines = lines.lines().collect::<Vec<_>>();
+
+        assert_eq!(lines.len(), 4);
+        let first = serde_json::from_str::<serde_json::Value>(lines[0]).unwrap();
+        assert_eq!(first["silver"], "24000");
+        assert_eq!(first["parse_ns"], 1_000);
+        assert_eq!(first["passed"], true);
+        let second = serde_json::from_str::<serde_json::Value>(lines[1]).unwrap();
+        assert_eq!(second["passed"], false);
+        assert_eq!(second["status"], "mismatch: silver was 1 instead of 15");
+    }
+
+    #[test]
+    fn test_markdown() {
+        let mut out = Vec::new();
+        render(MarkdownReporter::new(&mut out));
+        let table = String::from_utf8(out).unwrap();
+        let lines = table.lines().collect::<Vec<_>>();
+
+        assert_eq!(lines.len(), 6);
+        assert_eq!(
+            lines[2],
+            "| 1 | Calorie <Counting> | `24000` | `45000` | 1.00 µs | 2.00 µs | 3.00 µs | ok |"
+        );
+        assert!(lines[4].starts_with("| 3 | Calorie <Counting> |  |  |"));
+    }
+
+    #[test]
+    fn test_markdown_pipes() {
+        let mut out = Vec::new();
+        let mut reporter = MarkdownReporter::new(&mut out);
+        let mut report = reports().remove(0);
+        report.title = "Either | Or";
+        if let Ok(result) = &mut report.result {
+            result.gold = Output::String(String::from("a|b"));
+        }
+        reporter.day(report).unwrap();
+        reporter.finish().unwrap();
+
+        let table = String::from_utf8(out).unwrap();
+        assert!(table
+            .lines()
+            .nth(2)
+            .unwrap()
+            .starts_with("| 1 | Either \\| Or | `24000` | `a\\|b` |"));
+    }
+
+    #[test]
+    fn test_junit() {
+        let mut out = Vec::new();
+        render(JunitReporter::new(&mut out));
+        let xml = String::from_utf8(out).unwrap();
+
+        assert!(xml.contains(r#"tests="4" failures="1" errors="1" skipped="1""#));
+        assert!(xml.contains(r#"name="day_01 Calorie &lt;Counting&gt;" time="0.000006"/>"#));
+        assert!(xml.contains(r#"<failure message="mismatch: silver was 1 instead of 15"/>"#));
+        assert!(xml.contains(r#"<error message="failed: Unknown symbol"/>"#));
+        assert!(xml.contains(r#"<skipped message="skipped: no cached input"/>"#));
+        assert!(xml.trim_end().ends_with("</testsuite>"));
+    }
+
+    #[test]
+    fn test_terminal_picture() {
+        let picture = "#..#\n#..#\n####\n#..#\n#..#\n#..#";
+        let mut reports = reports();
+        if let Ok(result) = &mut reports[0].result {
+            result.gold = Letters::new(String::from(picture)).into();
+        }
+
+        let mut out = Vec::new();
+        let mut reporter = TerminalReporter::new(&mut out);
+        for report in reports {
+            reporter.day(report).unwrap();
+        }
+        reporter.finish().unwrap();
+        let output = String::from_utf8(out).unwrap();
+
+        assert!(output.contains("| H "));
+        assert!(output.ends_with(&format!("\nDay 1 gold:\n{}\n", picture)));
+    }
+}
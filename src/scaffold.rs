@@ -0,0 +1,163 @@
+//! Generate the module of a new day from `assignment_template.rs` and register it with its year.
+
+use std::path::{Path, PathBuf};
+
+use crate::{error::AdventError, examples::Example};
+
+const TEMPLATE: &str = include_str!("assignment_template.rs");
+
+/// The title of a puzzle, from the `--- Day 1: Calorie Counting ---` heading of its page
+pub fn title(html: &str) -> Option<String> {
+    let document = scraper::Html::parse_document(html);
+    let selector = scraper::Selector::parse("article.day-desc > h2").unwrap();
+    let heading = document
+        .select(&selector)
+        .next()?
+        .text()
+        .collect::<String>();
+
+    let (_, title) = heading.trim().trim_matches('-').split_once(':')?;
+    Some(title.trim().to_owned())
+}
+
+/// The module of a day, with the example input and answers filled in when they are known
+pub fn generate(example: Option<&Example>) -> String {
+    let example = match example {
+        Some(example) => example,
+        None => return TEMPLATE.to_owned(),
+    };
+
+    let mut module = TEMPLATE.replacen(
+        "static TEST_INPUT: &str = \"\";",
+        &format!("static TEST_INPUT: &str = {:?};", example.input),
+        1,
+    );
+    for (part, answer) in [("silver", &example.silver), ("gold", &example.gold)] {
+        if let Some(answer) = answer {
+            module = module.replacen(
+                &format!("{}: TEST_INPUT => -1;", part),
+                &format!("{}: TEST_INPUT => {};", part, literal(answer)),
+                1,
+            );
+        }
+    }
+    module
+}
+
+/// Numbers are compared as numbers, with a suffix when they don't fit an `i32`,
+/// anything else as a string
+fn literal(answer: &str) -> String {
+    match answer.parse::<i128>() {
+        Ok(number) if i32::try_from(number).is_ok() => number.to_string(),
+        Ok(number) if i64::try_from(number).is_ok() => format!("{}i64", number),
+        Ok(number) => format!("{}i128", number),
+        Err(_) => format!("{:?}", answer),
+    }
+}
+
+/// Add a day to the `register_days!` list of a year's `mod.rs`, keeping the days ordered
+pub fn register(mod_rs: &str, year: u16, day: u8, title: &str) -> Result<String, AdventError> {
+    let entry = format!("    {} => {}, {:?};", day, module_name(day), title);
+    let day_of = |line: &str| {
+        line.trim()
+            .split_once(" => ")
+            .and_then(|(day, _)| day.parse::<u8>().ok())
+    };
+
+    let mut lines = mod_rs.lines().collect::<Vec<&str>>();
+    if lines.iter().any(|line| day_of(line) == Some(day)) {
+        return Err(AdventError::DayExists(year, day));
+    }
+
+    let position = match lines
+        .iter()
+        .position(|line| day_of(line).is_some_and(|d| d > day))
+    {
+        Some(position) => position,
+        None => lines
+            .iter()
+            .rposition(|line| line.trim() == "}")
+            .ok_or(AdventError::MissingYear(year))?,
+    };
+    lines.insert(position, &entry);
+
+    Ok(lines.join("\n") + "\n")
+}
+
+fn module_name(day: u8) -> String {
+    format!("assignment_{}", day)
+}
+
+/// Write the module of a day into `src_dir/year_<year>` and register it, returning the new file
+pub fn create(
+    src_dir: &Path,
+    year: u16,
+    day: u8,
+    title: &str,
+    example: Option<&Example>,
+) -> Result<PathBuf, AdventError> {
+    let year_dir = src_dir.join(format!("year_{}", year));
+    let mod_rs = std::fs::read_to_string(year_dir.join("mod.rs"))
+        .map_err(|_| AdventError::MissingYear(year))?;
+    let module = year_dir.join(format!("{}.rs", module_name(day)));
+    if module.exists() {
+        return Err(AdventError::DayExists(year, day));
+    }
+
+    let registered = register(&mod_rs, year, day, title)?;
+    std::fs::write(&module, generate(example))?;
+    std::fs::write(year_dir.join("mod.rs"), registered)?;
+
+    Ok(module)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static MOD_RS: &str = "use crate::registry::register_days;\n\nregister_days! {\n    2022;\n    1 => assignment_1, \"Calorie Counting\";\n    3 => assignment_3, \"Rucksack Reorganization\";\n}\n";
+
+    #[test]
+    fn test_title() {
+        let html = "<main><article class=\"day-desc\"><h2>--- Day 2: Rock Paper Scissors ---</h2></article></main>";
+        assert_eq!(title(html).as_deref(), Some("Rock Paper Scissors"));
+        assert_eq!(title("<main></main>"), None);
+    }
+
+    #[test]
+    fn test_generate() {
+        assert_eq!(generate(None), TEMPLATE);
+
+        let example = Example {
+            input: String::from("A Y\nB \"X\"\n"),
+            silver: Some(String::from("15")),
+            gold: Some(String::from("CMZ")),
+            ..Default::default()
+        };
+        let module = generate(Some(&example));
+
+        assert!(module.contains("static TEST_INPUT: &str = \"A Y\\nB \\\"X\\\"\\n\";"));
+        assert!(module.contains("silver: TEST_INPUT => 15;"));
+        assert!(module.contains("gold: TEST_INPUT => \"CMZ\";"));
+        assert_eq!(literal("2713310158"), "2713310158i64");
+    }
+
+    #[test]
+    fn test_register() {
+        let registered = register(MOD_RS, 2022, 2, "Rock Paper Scissors").unwrap();
+        let days = registered
+            .lines()
+            .filter(|line| line.contains("=>"))
+            .collect::<Vec<&str>>();
+        assert_eq!(days[1], "    2 => assignment_2, \"Rock Paper Scissors\";");
+        assert_eq!(days.len(), 3);
+
+        let last = register(MOD_RS, 2022, 4, "Camp Cleanup").unwrap();
+        assert!(last.ends_with("    4 => assignment_4, \"Camp Cleanup\";\n}\n"));
+
+        assert!(matches!(
+            register(MOD_RS, 2022, 3, "Again"),
+            Err(AdventError::DayExists(2022, 3))
+        ));
+    }
+}
@@ -0,0 +1,65 @@
+//! Local state files in the data directory, like the submission history and the queue.
+//! Only a missing file means the state is empty: any other failure is returned, so a file
+//! that can't be read is never overwritten with an empty state.
+
+use std::{io, path::Path};
+
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::error::AdventError;
+
+/// The contents of a state file, or `None` when it doesn't exist yet
+pub fn read(path: &Path) -> Result<Option<String>, AdventError> {
+    match std::fs::read_to_string(path) {
+        Ok(contents) => Ok(Some(contents)),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(err) => Err(err.into()),
+    }
+}
+
+/// Load a JSON state file, starting from the default when there is none
+pub fn load<T: DeserializeOwned + Default>(path: &Path) -> Result<T, AdventError> {
+    match read(path)? {
+        Some(contents) => Ok(serde_json::from_str(&contents)?),
+        None => Ok(T::default()),
+    }
+}
+
+/// Write a state file as pretty JSON, creating its directory when needed
+pub fn save<T: Serialize>(path: &Path, state: &T) -> Result<(), AdventError> {
+    let contents = serde_json::to_string_pretty(state)?;
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    Ok(std::fs::write(path, contents)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::*;
+
+    #[test]
+    fn test_load() {
+        let dir = std::env::temp_dir().join(format!("advent_store_{}", std::process::id()));
+        let path = dir.join("state.json");
+        assert!(load::<BTreeMap<u8, String>>(&path).unwrap().is_empty());
+
+        let state = BTreeMap::from([(1, String::from("24000"))]);
+        save(&path, &state).unwrap();
+        assert_eq!(load::<BTreeMap<u8, String>>(&path).unwrap(), state);
+
+        std::fs::write(&path, "{\"1\": ").unwrap();
+        assert!(matches!(
+            load::<BTreeMap<u8, String>>(&path),
+            Err(AdventError::InvalidState(_))
+        ));
+        assert!(matches!(
+            load::<BTreeMap<u8, String>>(&dir),
+            Err(AdventError::Io(_))
+        ));
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
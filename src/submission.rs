@@ -0,0 +1,178 @@
+use std::{
+    fmt::{Display, Formatter, Result as DisplayResult},
+    time::Duration,
+};
+
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+/// How long to wait when a rate limit doesn't say, long enough not to hammer the server
+const DEFAULT_WAIT: Duration = Duration::from_secs(60);
+
+/// The outcome of posting an answer to Advent of Code
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SubmissionResult {
+    Correct,
+    TooHigh,
+    TooLow,
+    Incorrect,
+    RateLimited(Duration),
+    AlreadySolved,
+    WrongLevel,
+}
+
+impl SubmissionResult {
+    /// Classify the response page of an answer submission for the given `level`.
+    ///
+    /// Returns `None` when the page has no `<article>` or when its message is
+    /// not one Advent of Code is known to send.
+    pub fn from_html(html: &str, level: u8) -> Option<SubmissionResult> {
+        SubmissionResult::from_message(&article_text(html)?, level)
+    }
+
+    /// Classify the flattened text of a response `<article>`
+    pub fn from_message(message: &str, level: u8) -> Option<SubmissionResult> {
+        if message.contains("That's the right answer") {
+            Some(SubmissionResult::Correct)
+        } else if message.contains("That's not the right answer") {
+            if message.contains("too high") {
+                Some(SubmissionResult::TooHigh)
+            } else if message.contains("too low") {
+                Some(SubmissionResult::TooLow)
+            } else {
+                Some(SubmissionResult::Incorrect)
+            }
+        } else if message.contains("You gave an answer too recently") {
+            Some(SubmissionResult::RateLimited(
+                parse_wait(message).unwrap_or(DEFAULT_WAIT),
+            ))
+        } else if message.contains("You don't seem to be solving the right level") {
+            // Silver can only be the wrong level once it has been solved. Gold is the wrong
+            // level both while silver is open and once gold is solved, which the client
+            // settles with the puzzle page.
+            match level {
+                1 => Some(SubmissionResult::AlreadySolved),
+                _ => Some(SubmissionResult::WrongLevel),
+            }
+        } else {
+            None
+        }
+    }
+
+    /// Whether the submitted answer is known to be wrong
+    pub fn is_wrong(&self) -> bool {
+        matches!(
+            self,
+            SubmissionResult::TooHigh | SubmissionResult::TooLow | SubmissionResult::Incorrect
+        )
+    }
+}
+
+impl Display for SubmissionResult {
+    fn fmt(&self, f: &mut Formatter<'_>) -> DisplayResult {
+        match self {
+            SubmissionResult::Correct => write!(f, "That's the right answer!"),
+            SubmissionResult::TooHigh => write!(f, "That's not the right answer, it is too high"),
+            SubmissionResult::TooLow => write!(f, "That's not the right answer, it is too low"),
+            SubmissionResult::Incorrect => write!(f, "That's not the right answer"),
+            SubmissionResult::RateLimited(wait) => {
+                write!(f, "Answered too recently, wait {}s", wait.as_secs())
+            }
+            SubmissionResult::AlreadySolved => write!(f, "This level was already solved"),
+            SubmissionResult::WrongLevel => write!(f, "This level can't be solved yet"),
+        }
+    }
+}
+
+/// Flatten the text of the first `<article>` of a page, dropping the `[Return to Day N]` links
+pub fn article_text(html: &str) -> Option<String> {
+    let document = scraper::Html::parse_document(html);
+    let selector = scraper::Selector::parse("article").unwrap();
+
+    let article = document.select(&selector).next()?;
+
+    Some(
+        article
+            .text()
+            .collect::<String>()
+            .lines()
+            .filter(|l| !l.trim().starts_with('['))
+            .fold(String::from(""), |acc, line| acc + line.trim())
+            .replace("You guessed", "You guessed: "),
+    )
+}
+
+/// Parse the remaining wait out of a message like "You have 4m 12s left to wait"
+fn parse_wait(message: &str) -> Option<Duration> {
+    let wait_finder =
+        Regex::new(r"You have (?:(?P<minutes>\d+)m )?(?P<seconds>\d+)s left to wait").unwrap();
+    let caps = wait_finder.captures(message)?;
+
+    let minutes = caps
+        .name("minutes")
+        .map_or(Some(0), |m| m.as_str().parse::<u64>().ok())?;
+    let seconds = caps.name("seconds")?.as_str().parse::<u64>().ok()?;
+
+    Some(Duration::from_secs(minutes * 60 + seconds))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static CORRECT: &str = include_str!("../tests/fixtures/submission/correct.html");
+    static TOO_HIGH: &str = include_str!("../tests/fixtures/submission/too_high.html");
+    static TOO_LOW: &str = include_str!("../tests/fixtures/submission/too_low.html");
+    static INCORRECT: &str = include_str!("../tests/fixtures/submission/incorrect.html");
+    static RATE_LIMITED: &str = include_str!("../tests/fixtures/submission/rate_limited.html");
+    static WRONG_LEVEL: &str = include_str!("../tests/fixtures/submission/wrong_level.html");
+
+    #[test]
+    fn test_answers() {
+        assert_eq!(
+            SubmissionResult::from_html(CORRECT, 1),
+            Some(SubmissionResult::Correct)
+        );
+        assert_eq!(
+            SubmissionResult::from_html(TOO_HIGH, 1),
+            Some(SubmissionResult::TooHigh)
+        );
+        assert_eq!(
+            SubmissionResult::from_html(TOO_LOW, 2),
+            Some(SubmissionResult::TooLow)
+        );
+        assert_eq!(
+            SubmissionResult::from_html(INCORRECT, 2),
+            Some(SubmissionResult::Incorrect)
+        );
+    }
+
+    #[test]
+    fn test_rate_limited() {
+        assert_eq!(
+            SubmissionResult::from_html(RATE_LIMITED, 1),
+            Some(SubmissionResult::RateLimited(Duration::from_secs(252)))
+        );
+        assert_eq!(
+            parse_wait("You have 36s left to wait."),
+            Some(Duration::from_secs(36))
+        );
+        assert_eq!(
+            SubmissionResult::from_message("You gave an answer too recently.", 1),
+            Some(SubmissionResult::RateLimited(DEFAULT_WAIT))
+        );
+    }
+
+    #[test]
+    fn test_wrong_level() {
+        assert_eq!(
+            SubmissionResult::from_html(WRONG_LEVEL, 1),
+            Some(SubmissionResult::AlreadySolved)
+        );
+        assert_eq!(
+            SubmissionResult::from_html(WRONG_LEVEL, 2),
+            Some(SubmissionResult::WrongLevel)
+        );
+        assert_eq!(SubmissionResult::from_html("<html></html>", 1), None);
+    }
+}
@@ -0,0 +1,259 @@
+use std::{
+    cell::Cell,
+    panic::{self, AssertUnwindSafe},
+    sync::Once,
+};
+
+use crate::{answers::Verdict, registry::Day, RunResult};
+
+/// The outcome of running a single day as part of a larger run
+#[derive(Debug)]
+pub struct DayReport {
+    pub year: u16,
+    pub day: u8,
+    pub title: &'static str,
+    /// The answers and timings, or why the day could not be solved
+    pub result: Result<RunResult, String>,
+    /// How the answers compare to the accepted answers, when they were verified
+    pub verdict: Option<Verdict>,
+}
+
+impl DayReport {
+    pub fn new(registered: &Day, result: Result<RunResult, String>) -> DayReport {
+        DayReport {
+            year: registered.year,
+            day: registered.day,
+            title: registered.title,
+            result,
+            verdict: None,
+        }
+    }
+
+    pub fn with_verdict(mut self, verdict: Verdict) -> DayReport {
+        self.verdict = Some(verdict);
+        self
+    }
+
+    /// Whether this day should make the whole run fail
+    pub fn is_failure(&self) -> bool {
+        match &self.verdict {
+            Some(verdict) => verdict.is_failure(),
+            None => self.result.is_err(),
+        }
+    }
+
+    /// A single line describing how the day went
+    pub fn status(&self) -> String {
+        let status = match (&self.verdict, &self.result) {
+            (Some(verdict), _) => verdict.to_string(),
+            (None, Ok(_)) => String::from("ok"),
+            (None, Err(reason)) => format!("failed: {}", reason),
+        };
+        status.lines().next().unwrap_or_default().to_owned()
+    }
+}
+
+thread_local! {
+    /// Whether the current thread is inside [`catch_panic`], which reports panics itself
+    static QUIET: Cell<bool> = const { Cell::new(false) };
+}
+
+static QUIET_HOOK: Once = Once::new();
+
+/// Run `f` and turn a panic into its message, without printing it to stderr.
+/// Panics of other threads still reach the hook that was installed before.
+pub fn catch_panic<T>(f: impl FnOnce() -> T) -> Result<T, String> {
+    QUIET_HOOK.call_once(|| {
+        let hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if !QUIET.get() {
+                hook(info);
+            }
+        }));
+    });
+
+    let quiet = QUIET.replace(true);
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    QUIET.set(quiet);
+
+    result.map_err(|payload| {
+        payload
+            .downcast_ref::<&str>()
+            .map(|message| message.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| String::from("panicked"))
+    })
+}
+
+/// Solve a day, turning both errors and panics into a message
+pub fn catch_solve(registered: &Day, input: String) -> Result<RunResult, String> {
+    catch_panic(|| registered.solve(input))?.map_err(|err| err.to_string())
+}
+
+/// Show a duration in nanoseconds with a unit that keeps it readable
+pub fn format_time(nanos: u128) -> String {
+    match nanos {
+        0..=999 => format!("{} ns", nanos),
+        1_000..=999_999 => format!("{:.2} µs", nanos as f64 / 1_000.0),
+        1_000_000..=999_999_999 => format!("{:.2} ms", nanos as f64 / 1_000_000.0),
+        _ => format!("{:.2} s", nanos as f64 / 1_000_000_000.0),
+    }
+}
+
+/// Answers that span several lines, like a picture of letters, don't fit in a cell
+pub fn format_answer(answer: &str) -> String {
+    match answer.trim().lines().count() {
+        0 | 1 => answer.trim().to_owned(),
+        lines => format!("<{} lines>", lines),
+    }
+}
+
+/// Render all reports as one table, closed by a row with the total time and how many days succeeded
+pub fn table(reports: &[DayReport]) -> String {
+    let header = ["Day", "Silver", "Gold", "Parse", "Silver", "Gold", "Status"].map(String::from);
+    let mut rows = vec![header];
+    let mut totals = [0u128; 3];
+    let mut ok = 0;
+
+    for report in reports {
+        if !report.is_failure() {
+            ok += 1;
+        }
+        let row = match &report.result {
+            Ok(result) => {
+                let times = [result.parse_time, result.silver_time, result.gold_time];
+                for (total, time) in totals.iter_mut().zip(times) {
+                    *total += time;
+                }
+                [
+                    report.day.to_string(),
+                    format_answer(&result.silver.to_string()),
+                    format_answer(&result.gold.to_string()),
+                    format_time(result.parse_time),
+                    format_time(result.silver_time),
+                    format_time(result.gold_time),
+                    report.status(),
+                ]
+            }
+            Err(_) => [
+                report.day.to_string(),
+                String::from("-"),
+                String::from("-"),
+                String::from("-"),
+                String::from("-"),
+                String::from("-"),
+                report.status(),
+            ],
+        };
+        rows.push(row);
+    }
+
+    rows.push([
+        String::from("Total"),
+        String::new(),
+        String::new(),
+        format_time(totals[0]),
+        format_time(totals[1]),
+        format_time(totals[2]),
+        format!(
+            "{}/{} ok, {}",
+            ok,
+            reports.len(),
+            format_time(totals.iter().sum())
+        ),
+    ]);
+
+    let mut widths = [0; 7];
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let separator = widths
+        .iter()
+        .map(|width| "-".repeat(width + 2))
+        .collect::<Vec<_>>()
+        .join("+");
+    let separator = format!("+{}+\n", separator);
+
+    let mut table = separator.clone();
+    for (index, row) in rows.iter().enumerate() {
+        let cells = row
+            .iter()
+            .zip(widths)
+            .map(|(cell, width)| format!(" {}{} ", cell, " ".repeat(width - cell.chars().count())))
+            .collect::<Vec<_>>()
+            .join("|");
+        table.push_str(&format!("|{}|\n", cells));
+        if index == 0 || index == rows.len() - 2 {
+            table.push_str(&separator);
+        }
+    }
+    table.push_str(&separator);
+
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Output;
+
+    fn report(day: u8, result: Result<RunResult, String>) -> DayReport {
+        DayReport {
+            year: 2022,
+            day,
+            title: "Test",
+            result,
+            verdict: None,
+        }
+    }
+
+    #[test]
+    fn test_catch_panic() {
+        assert_eq!(catch_panic(|| 42), Ok(42));
+        assert_eq!(
+            catch_panic(|| -> u8 { panic!("Could not parse silver input") }),
+            Err(String::from("Could not parse silver input"))
+        );
+        assert_eq!(
+            catch_panic(|| -> u8 { panic!("day {}", 7) }),
+            Err(String::from("day 7"))
+        );
+        assert_eq!(
+            catch_panic(|| catch_panic(|| -> u8 { panic!("inner") })),
+            Ok(Err(String::from("inner")))
+        );
+        assert!(!QUIET.get());
+    }
+
+    #[test]
+    fn test_table() {
+        let reports = [
+            report(
+                1,
+                Ok(RunResult {
+                    silver: Output::U32(24000),
+                    gold: Output::String(String::from("#..#\n#..#\n####")),
+                    parse_time: 10,
+                    silver_time: 1_500_000,
+                    gold_time: 2_000_000_000,
+                }),
+            ),
+            report(2, Err(String::from("Unknown symbol"))),
+        ];
+        let table = table(&reports);
+        let lines = table.lines().collect::<Vec<_>>();
+
+        assert_eq!(lines.len(), 8);
+        assert!(lines[3].contains("24000") && lines[3].contains("<3 lines>"));
+        assert!(lines[3].contains("1.50 ms") && lines[3].contains("2.00 s"));
+        assert!(lines[4].contains("failed: Unknown symbol"));
+        assert!(lines[3].contains("10 ns"));
+        assert!(lines[6].starts_with("| Total") && lines[6].contains("1/2 ok"));
+        assert!(lines
+            .iter()
+            .all(|line| line.chars().count() == lines[0].len()));
+    }
+}
@@ -0,0 +1,161 @@
+//! The tests every assignment shares: its examples, and the real puzzle input once it is cached.
+
+use crate::{
+    answers::{AnswerStore, Verdict},
+    client::{AocClient, DEFAULT_BASE_URL},
+    registry,
+};
+
+/// The year and day of an assignment module, from a path like `advent_2022::year_2022::assignment_1`
+fn day_of(module_path: &str) -> Option<(u16, u8)> {
+    let mut segments = module_path.split("::");
+    let year = segments.find_map(|segment| segment.strip_prefix("year_"))?;
+    let day = segments.next()?.strip_prefix("assignment_")?;
+
+    Some((year.parse().ok()?, day.parse().ok()?))
+}
+
+/// Solve the cached puzzle input of the assignment in `module_path` and check it gives the
+/// accepted answers. Without a cached input or accepted answers there is nothing to check.
+pub fn check_real_input(module_path: &str) {
+    let (year, day) =
+        day_of(module_path).expect("assignment modules are year_<year>::assignment_<day>");
+    let client = AocClient::new(DEFAULT_BASE_URL, None, crate::data_dir().unwrap());
+
+    let input = match client.cached_input(year, day) {
+        Some(input) => input,
+        None => return eprintln!("Skipping day {} of {}, its input is not cached", day, year),
+    };
+    let answers = match AnswerStore::load(&client.existing_year_dir(year))
+        .unwrap()
+        .get(day)
+    {
+        Some(answers) => answers.clone(),
+        None => {
+            return eprintln!(
+                "Skipping day {} of {}, no accepted answers, store them with `seed`",
+                day, year
+            )
+        }
+    };
+
+    let registered = registry::find(year, day).expect("the assignment is registered");
+    let result = registered
+        .solve(input)
+        .unwrap_or_else(|err| panic!("{}", err.snippet(&format!("input of day {}", day))));
+
+    let verdict = Verdict::check(&answers, &result);
+    assert!(
+        !verdict.is_failure(),
+        "day {} of {}: {}",
+        day,
+        year,
+        verdict
+    );
+}
+
+/// Generate the tests of an assignment: `test_silver` and `test_gold` solve examples with
+/// optional params, and the ignored `test_real_input` checks the cached puzzle input against
+/// the accepted answers. Examples are either listed per part as `input => answer`, or given
+/// as `(input, silver, gold)` tuples.
+///
+/// ```ignore
+/// assignment_tests! {
+///     params: Params { row: 10, bound: 20 };
+///     silver: TEST_INPUT => 26;
+///     gold: TEST_INPUT => 56_000_011;
+/// }
+/// ```
+macro_rules! assignment_tests {
+    (@params) => { Default::default() };
+    (@params $params:expr) => { $params };
+
+    (@common $($params:expr)?) => {
+        fn example_params() -> <Solution as $crate::Assignment>::Params {
+            $crate::testing::assignment_tests!(@params $($params)?)
+        }
+
+        #[test]
+        #[ignore = "needs the cached puzzle input, run with --ignored"]
+        fn test_real_input() {
+            $crate::testing::check_real_input(module_path!());
+        }
+    };
+
+    (
+        $(params: $params:expr;)?
+        examples: $examples:expr;
+    ) => {
+        #[test]
+        fn test_silver() {
+            use $crate::Assignment as _;
+            let sol = Solution::new();
+            for (i, (raw, expected, _)) in $examples.iter().enumerate() {
+                let input = sol.parse_input(raw).unwrap();
+                let result = sol.silver(&input, &example_params()).unwrap();
+                assert_eq!(result, expected, "silver of example {}", i + 1);
+            }
+        }
+
+        #[test]
+        fn test_gold() {
+            use $crate::Assignment as _;
+            let sol = Solution::new();
+            for (i, (raw, _, expected)) in $examples.iter().enumerate() {
+                let input = sol.parse_input(raw).unwrap();
+                let result = sol.gold(&input, &example_params()).unwrap();
+                assert_eq!(result, expected, "gold of example {}", i + 1);
+            }
+        }
+
+        $crate::testing::assignment_tests!(@common $($params)?);
+    };
+
+    (
+        $(params: $params:expr;)?
+        silver: $($silver_input:expr => $silver:expr),+;
+        $(gold: $($gold_input:expr => $gold:expr),+;)?
+    ) => {
+        #[test]
+        fn test_silver() {
+            use $crate::Assignment as _;
+            let sol = Solution::new();
+            $(
+                let input = sol.parse_input($silver_input).unwrap();
+                let result = sol.silver(&input, &example_params()).unwrap();
+                assert_eq!(result, $silver, "silver of {}", stringify!($silver_input));
+            )+
+        }
+
+        $(
+            #[test]
+            fn test_gold() {
+                use $crate::Assignment as _;
+                let sol = Solution::new();
+                $(
+                    let input = sol.parse_input($gold_input).unwrap();
+                    let result = sol.gold(&input, &example_params()).unwrap();
+                    assert_eq!(result, $gold, "gold of {}", stringify!($gold_input));
+                )+
+            }
+        )?
+
+        $crate::testing::assignment_tests!(@common $($params)?);
+    };
+}
+
+pub(crate) use assignment_tests;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_day_of() {
+        assert_eq!(
+            day_of("advent_2022::year_2022::assignment_15::tests"),
+            Some((2022, 15))
+        );
+        assert_eq!(day_of("advent_2022::registry"), None);
+    }
+}
@@ -0,0 +1,67 @@
+use crate::{error::SolveError, parse, Assignment, Output};
+
+pub struct Solution {}
+
+impl Solution {
+    pub fn new() -> Solution {
+        Solution {}
+    }
+}
+
+impl Assignment for Solution {
+    type Input = Vec<i32>;
+    type Output = Output;
+    type Params = ();
+
+    fn parse_input(&self, input: &str) -> Result<Self::Input, SolveError> {
+        let mut result = Vec::new();
+        let mut current = 0;
+        for line in parse::lines(input) {
+            match line.text {
+                "" => {
+                    result.push(current);
+                    current = 0;
+                }
+                text => current += line.parse::<i32>(text)?,
+            }
+        }
+        result.push(current);
+        Ok(result)
+    }
+
+    fn silver(
+        &self,
+        input: &Self::Input,
+        _params: &Self::Params,
+    ) -> Result<Self::Output, SolveError> {
+        let max = input
+            .iter()
+            .max()
+            .ok_or_else(|| SolveError::new("There are no elves"))?;
+        Ok(max.to_owned().into())
+    }
+
+    fn gold(
+        &self,
+        input: &Self::Input,
+        _params: &Self::Params,
+    ) -> Result<Self::Output, SolveError> {
+        let mut clone_input = input.clone();
+        clone_input.sort();
+        Ok(clone_input.iter().rev().take(3).sum::<i32>().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::assignment_tests;
+
+    static TEST_INPUT: &str =
+        "1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000\n";
+
+    assignment_tests! {
+        silver: TEST_INPUT => 24000;
+        gold: TEST_INPUT => 45000;
+    }
+}
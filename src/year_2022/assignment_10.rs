@@ -1,6 +1,4 @@
-use std::str::FromStr;
-
-use crate::{Assignment, Output};
+use crate::{error::SolveError, ocr::Letters, parse, Assignment, Output};
 
 #[derive(Debug)]
 pub enum Instruction {
@@ -8,22 +6,9 @@ pub enum Instruction {
     Add(i32),
 }
 
-impl FromStr for Instruction {
-    type Err = String;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (key, value) = s.split_once(' ').unwrap_or((s, ""));
-        match key {
-            "noop" => Ok(Instruction::Noop),
-            "addx" => Ok(Instruction::Add(value.parse().unwrap())),
-            _ => Err(format!("Could not parse {}", s)),
-        }
-    }
-}
-
-impl Into<(i32, i32)> for &Instruction {
-    fn into(self) -> (i32, i32) {
-        match self {
+impl From<&Instruction> for (i32, i32) {
+    fn from(val: &Instruction) -> Self {
+        match val {
             Instruction::Noop => (0, 1),
             Instruction::Add(value) => (*value, 2),
         }
@@ -41,12 +26,26 @@ impl Solution {
 impl Assignment for Solution {
     type Input = Vec<Instruction>;
     type Output = Output;
-
-    fn parse_input(&self, input: &str) -> Option<Self::Input> {
-        Some(input.lines().map(|l| l.parse().unwrap()).collect())
+    type Params = ();
+
+    fn parse_input(&self, input: &str) -> Result<Self::Input, SolveError> {
+        parse::lines(input)
+            .map(|line| {
+                let (key, value) = line.text.split_once(' ').unwrap_or((line.text, ""));
+                match key {
+                    "noop" => Ok(Instruction::Noop),
+                    "addx" => Ok(Instruction::Add(line.parse(value)?)),
+                    _ => Err(line.error(key, "Unknown instruction, expected noop or addx")),
+                }
+            })
+            .collect()
     }
 
-    fn silver(&self, input: &Self::Input) -> Option<Self::Output> {
+    fn silver(
+        &self,
+        input: &Self::Input,
+        _params: &Self::Params,
+    ) -> Result<Self::Output, SolveError> {
         let mut result = 0;
         let mut x = 1;
         let mut cycle = 0;
@@ -67,10 +66,14 @@ impl Assignment for Solution {
             x += d_x;
         }
 
-        Some((result).into())
+        Ok((result).into())
     }
 
-    fn gold(&self, input: &Self::Input) -> Option<Self::Output> {
+    fn gold(
+        &self,
+        input: &Self::Input,
+        _params: &Self::Params,
+    ) -> Result<Self::Output, SolveError> {
         let mut screen = [' '; 240];
         let mut x: i32 = 1;
         let mut cycle: i32 = 0;
@@ -80,7 +83,10 @@ impl Assignment for Solution {
 
             for _ in 0..d_cycle {
                 if ((cycle % 40) - x).abs() <= 1 {
-                    screen[cycle as usize] = '█';
+                    let pixel = screen.get_mut(cycle as usize).ok_or_else(|| {
+                        SolveError::new("The program runs longer than the screen is drawn")
+                    })?;
+                    *pixel = '█';
                 }
                 cycle += 1;
             }
@@ -93,31 +99,20 @@ impl Assignment for Solution {
             .map(|arr| arr.iter().collect::<String>())
             .collect::<Vec<String>>()
             .join("\n");
-        println!("{}", a);
 
-        Some((a).into())
+        Ok(Letters::new(a).into())
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::testing::assignment_tests;
 
     static TEST_INPUT: &str = "addx 15\naddx -11\naddx 6\naddx -3\naddx 5\naddx -1\naddx -8\naddx 13\naddx 4\nnoop\naddx -1\naddx 5\naddx -1\naddx 5\naddx -1\naddx 5\naddx -1\naddx 5\naddx -1\naddx -35\naddx 1\naddx 24\naddx -19\naddx 1\naddx 16\naddx -11\nnoop\nnoop\naddx 21\naddx -15\nnoop\nnoop\naddx -3\naddx 9\naddx 1\naddx -3\naddx 8\naddx 1\naddx 5\nnoop\nnoop\nnoop\nnoop\nnoop\naddx -36\nnoop\naddx 1\naddx 7\nnoop\nnoop\nnoop\naddx 2\naddx 6\nnoop\nnoop\nnoop\nnoop\nnoop\naddx 1\nnoop\nnoop\naddx 7\naddx 1\nnoop\naddx -13\naddx 13\naddx 7\nnoop\naddx 1\naddx -33\nnoop\nnoop\nnoop\naddx 2\nnoop\nnoop\nnoop\naddx 8\nnoop\naddx -1\naddx 2\naddx 1\nnoop\naddx 17\naddx -9\naddx 1\naddx 1\naddx -3\naddx 11\nnoop\nnoop\naddx 1\nnoop\naddx 1\nnoop\nnoop\naddx -13\naddx -19\naddx 1\naddx 3\naddx 26\naddx -30\naddx 12\naddx -1\naddx 3\naddx 1\nnoop\nnoop\nnoop\naddx -9\naddx 18\naddx 1\naddx 2\nnoop\nnoop\naddx 9\nnoop\nnoop\nnoop\naddx -1\naddx 2\naddx -37\naddx 1\naddx 3\nnoop\naddx 15\naddx -21\naddx 22\naddx -6\naddx 1\nnoop\naddx 2\naddx 1\nnoop\naddx -10\nnoop\nnoop\naddx 20\naddx 1\naddx 2\naddx 2\naddx -6\naddx -11\nnoop\nnoop\nnoop";
 
-    #[test]
-    fn test_silver() {
-        let sol = Solution::new();
-        let input = sol.parse_input(TEST_INPUT);
-        let result = sol.silver(&input.unwrap()).unwrap();
-        assert_eq!(result, 13140)
-    }
-
-    #[test]
-    fn test_gold() {
-        let sol = Solution::new();
-        let input = sol.parse_input(TEST_INPUT);
-        let result = sol.gold(&input.unwrap()).unwrap();
-        assert_eq!(result, "██  ██  ██  ██  ██  ██  ██  ██  ██  ██  \n███   ███   ███   ███   ███   ███   ███ \n████    ████    ████    ████    ████    \n█████     █████     █████     █████     \n██████      ██████      ██████      ████\n███████       ███████       ███████     ")
+    assignment_tests! {
+        silver: TEST_INPUT => 13140;
+        gold: TEST_INPUT => "██  ██  ██  ██  ██  ██  ██  ██  ██  ██  \n███   ███   ███   ███   ███   ███   ███ \n████    ████    ████    ████    ████    \n█████     █████     █████     █████     \n██████      ██████      ██████      ████\n███████       ███████       ███████     ";
     }
 }
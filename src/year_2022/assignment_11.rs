@@ -1,8 +1,11 @@
-use std::str::FromStr;
-
 use regex::Regex;
 
-use crate::{Assignment, Output};
+use crate::{
+    error::SolveError,
+    params::params,
+    parse::{self, Line},
+    Assignment, Output,
+};
 
 #[derive(Debug, Clone, Copy)]
 enum MonkeyOperators {
@@ -73,54 +76,52 @@ impl Monkey {
     }
 }
 
-impl FromStr for Monkey {
-    type Err = String;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let lines = s.lines().skip(1).collect::<Vec<&str>>();
+impl Monkey {
+    /// Parse the lines describing a monkey, of which the first is its name
+    fn from_lines(lines: &[Line]) -> Result<Monkey, SolveError> {
+        let line = |index: usize| {
+            lines.get(index).copied().ok_or_else(|| {
+                lines[0].error(lines[0].text, "Expected six lines describing a monkey")
+            })
+        };
+        fn last_number<T>(line: Line) -> Result<T, SolveError>
+        where
+            T: std::str::FromStr,
+            T::Err: std::fmt::Display,
+        {
+            line.parse(line.text.rsplit(' ').next().unwrap_or_default())
+        }
 
         let digit_regex = Regex::new(r"\d+").unwrap();
-
+        let items_line = line(1)?;
         let starting_items = digit_regex
-            .find_iter(lines.first().unwrap())
-            .map(|found| found.as_str().parse::<u128>().unwrap())
-            .collect::<Vec<u128>>();
-
-        let operator = &lines.get(1).unwrap().split(' ').collect::<Vec<&str>>()[6..=7];
-        let bla = match operator[0] {
-            "+" => MonkeyOperators::Add(operator[1].parse::<u128>().unwrap()),
-            "*" => match operator[1].parse::<u128>() {
-                Ok(digit) => MonkeyOperators::Multiplication(digit),
-                Err(_) => MonkeyOperators::MultiplicationSelf,
-            },
-            _ => panic!("cannot parse {:?}", operator),
+            .find_iter(items_line.text)
+            .map(|found| items_line.parse::<u128>(found.as_str()))
+            .collect::<Result<Vec<u128>, _>>()?;
+
+        let operation_line = line(2)?;
+        let (_, formula) = operation_line.split(operation_line.text, "old ")?;
+        let (operator, value) = operation_line.split(formula, " ")?;
+        let operation = match (operator, value) {
+            ("*", "old") => MonkeyOperators::MultiplicationSelf,
+            ("+", value) => MonkeyOperators::Add(operation_line.parse(value)?),
+            ("*", value) => MonkeyOperators::Multiplication(operation_line.parse(value)?),
+            (operator, _) => {
+                return Err(operation_line.error(operator, "Unknown operator, expected + or *"))
+            }
         };
 
-        let test_division = *digit_regex
-            .find_iter(lines.get(2).unwrap())
-            .map(|found| found.as_str().parse::<u128>().unwrap())
-            .collect::<Vec<u128>>()
-            .first()
-            .unwrap();
-        let true_monkey = *digit_regex
-            .find_iter(lines.get(3).unwrap())
-            .map(|found| found.as_str().parse::<usize>().unwrap())
-            .collect::<Vec<usize>>()
-            .first()
-            .unwrap();
-        let false_monkey = *digit_regex
-            .find_iter(lines.get(4).unwrap())
-            .map(|found| found.as_str().parse::<usize>().unwrap())
-            .collect::<Vec<usize>>()
-            .first()
-            .unwrap();
+        let test_division = last_number(line(3)?)?;
+        if test_division == 0 {
+            return Err(line(3)?.error("0", "Can't test divisibility by 0"));
+        }
 
         Ok(Monkey {
             items: starting_items,
-            operation: bla,
+            operation,
             test_division,
-            true_monkey,
-            false_monkey,
+            true_monkey: last_number(line(4)?)?,
+            false_monkey: last_number(line(5)?)?,
         })
     }
 }
@@ -156,6 +157,15 @@ fn throw_items(monkeys: Vec<Monkey>, is_worried: &bool) -> (Vec<Monkey>, Vec<usi
     (new_monkeys, total_inspected)
 }
 
+params! {
+    pub struct Params {
+        /// Rounds of monkey business while relief keeps worries manageable
+        silver_rounds: usize = 20,
+        /// Rounds of monkey business without relief
+        gold_rounds: usize = 10_000,
+    }
+}
+
 pub struct Solution {}
 
 impl Solution {
@@ -167,21 +177,39 @@ impl Solution {
 impl Assignment for Solution {
     type Input = Vec<Monkey>;
     type Output = Output;
-
-    fn parse_input(&self, input: &str) -> Option<Self::Input> {
-        Some(
-            input
-                .split("\n\n")
-                .map(|monkey_string| monkey_string.parse().unwrap())
-                .collect(),
-        )
+    type Params = Params;
+
+    fn parse_input(&self, input: &str) -> Result<Self::Input, SolveError> {
+        let lines = parse::lines(input).collect::<Vec<_>>();
+        let monkeys = lines
+            .split(|line| line.text.is_empty())
+            .filter(|block| !block.is_empty())
+            .map(Monkey::from_lines)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        for (index, monkey) in monkeys.iter().enumerate() {
+            if let Some(target) = [monkey.true_monkey, monkey.false_monkey]
+                .into_iter()
+                .find(|target| *target >= monkeys.len())
+            {
+                return Err(SolveError::new(format!(
+                    "Monkey {} throws to monkey {}, which does not exist",
+                    index, target
+                )));
+            }
+        }
+        Ok(monkeys)
     }
 
-    fn silver(&self, input: &Self::Input) -> Option<Self::Output> {
+    fn silver(
+        &self,
+        input: &Self::Input,
+        params: &Self::Params,
+    ) -> Result<Self::Output, SolveError> {
         let mut cloned_input = input.clone();
         let mut inspected = vec![0; cloned_input.len()];
 
-        for _ in 0..20 {
+        for _ in 0..params.silver_rounds {
             let (new_monkeys, new_inspected) = throw_items(cloned_input, &false);
             cloned_input = new_monkeys;
 
@@ -193,21 +221,19 @@ impl Assignment for Solution {
         inspected.sort();
         inspected.reverse();
 
-        Some(
-            (inspected
-                .into_iter()
-                .take(2)
-                .map(|i| i as u32)
-                .product::<u32>())
-            .into(),
-        )
+        Ok((inspected
+            .into_iter()
+            .take(2)
+            .map(|i| i as u32)
+            .product::<u32>())
+        .into())
     }
 
-    fn gold(&self, input: &Self::Input) -> Option<Self::Output> {
+    fn gold(&self, input: &Self::Input, params: &Self::Params) -> Result<Self::Output, SolveError> {
         let mut cloned_input = input.clone();
         let mut inspected = vec![0; cloned_input.len()];
 
-        for _ in 0..10_000 {
+        for _ in 0..params.gold_rounds {
             let (new_monkeys, new_inspected) = throw_items(cloned_input, &true);
             cloned_input = new_monkeys;
 
@@ -219,20 +245,19 @@ impl Assignment for Solution {
         inspected.sort();
         inspected.reverse();
 
-        Some(
-            (inspected
-                .into_iter()
-                .take(2)
-                .map(|i| i as u128)
-                .product::<u128>())
-            .into(),
-        )
+        Ok((inspected
+            .into_iter()
+            .take(2)
+            .map(|i| i as u128)
+            .product::<u128>())
+        .into())
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::testing::assignment_tests;
 
     static TEST_INPUT: &str = "Monkey 0:
   Starting items: 79, 98
@@ -262,20 +287,8 @@ Monkey 3:
     If true: throw to monkey 0
     If false: throw to monkey 1";
 
-    #[test]
-    fn test_silver() {
-        let sol = Solution::new();
-        let input = sol.parse_input(TEST_INPUT);
-        let result = sol.silver(&input.unwrap()).unwrap();
-        assert_eq!(result, 10605)
-    }
-
-    #[test]
-    fn test_gold() {
-        let sol = Solution::new();
-        let input = sol.parse_input(TEST_INPUT);
-        let result = sol.gold(&input.unwrap()).unwrap();
-        let tested_result: u32 = 2713310158;
-        assert_eq!(result, tested_result)
+    assignment_tests! {
+        silver: TEST_INPUT => 10605;
+        gold: TEST_INPUT => 2_713_310_158u64;
     }
 }
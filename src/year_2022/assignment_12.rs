@@ -0,0 +1,223 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{error::SolveError, parse, Assignment, Output};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+struct Coord {
+    x: usize,
+    y: usize,
+}
+
+impl Coord {
+    fn new(y: usize, x: usize) -> Coord {
+        Coord { x, y }
+    }
+}
+
+// impl Ord for Coord {}
+
+#[derive(Debug)]
+pub struct Grid {
+    elevations: Vec<Vec<u32>>,
+    start: Coord,
+    end: Coord,
+}
+
+impl Grid {
+    fn possible_next(&self, current_position: &Coord) -> Vec<Coord> {
+        let mut possibilities = Vec::new();
+        let current_elevation = self.elevations[current_position.y][current_position.x];
+        let y_size = self.elevations.len();
+        let x_size = self.elevations[0].len();
+
+        if current_position.y > 0
+            && self.elevations[current_position.y - 1][current_position.x] <= current_elevation + 1
+        {
+            possibilities.push(Coord::new(current_position.y - 1, current_position.x));
+        }
+        if current_position.y < y_size - 1
+            && self.elevations[current_position.y + 1][current_position.x] <= current_elevation + 1
+        {
+            possibilities.push(Coord::new(current_position.y + 1, current_position.x));
+        }
+        if current_position.x > 0
+            && self.elevations[current_position.y][current_position.x - 1] <= current_elevation + 1
+        {
+            possibilities.push(Coord::new(current_position.y, current_position.x - 1));
+        }
+        if current_position.x < x_size - 1
+            && self.elevations[current_position.y][current_position.x + 1] <= current_elevation + 1
+        {
+            possibilities.push(Coord::new(current_position.y, current_position.x + 1));
+        }
+
+        possibilities
+    }
+}
+
+impl Grid {
+    fn parse(input: &str) -> Result<Grid, SolveError> {
+        let a_index = ('a' as u32) - 1;
+
+        let mut start = None;
+        let mut end = None;
+
+        let elevations = parse::lines(input)
+            .map(|line| {
+                line.text
+                    .char_indices()
+                    .enumerate()
+                    .map(|(j, (index, c))| match c {
+                        // 'S' => 0,
+                        'S' => {
+                            end = Some(Coord::new(line.number, j));
+                            Ok(26)
+                        }
+                        // 'E' => 27,
+                        'E' => {
+                            start = Some(Coord::new(line.number, j));
+                            Ok(1)
+                        }
+                        'a'..='z' => Ok(27 - ((c as u32) - a_index)),
+                        c => Err(line.error(
+                            &line.text[index..index + c.len_utf8()],
+                            "Not an elevation, expected a-z, S or E",
+                        )),
+                    })
+                    .collect()
+            })
+            .collect::<Result<Vec<Vec<u32>>, _>>()?;
+
+        let width = elevations.first().map_or(0, Vec::len);
+        if let Some((line, _)) = parse::lines(input)
+            .zip(&elevations)
+            .find(|(_, row)| row.len() != width)
+        {
+            return Err(line.error(
+                line.text,
+                format!("Expected {} squares like the first row", width),
+            ));
+        }
+
+        Ok(Grid {
+            elevations,
+            // The search goes down from the best signal, so it starts at E and ends at S
+            start: start
+                .ok_or_else(|| SolveError::new("There is no square E with the best signal"))?,
+            end: end.ok_or_else(|| SolveError::new("There is no square S to start from"))?,
+        })
+    }
+}
+
+/// The closest square that was reached but not visited yet, with its distance
+fn closest_unvisited(
+    distances: &HashMap<Coord, u32>,
+    visited: &HashSet<Coord>,
+) -> Option<(Coord, u32)> {
+    distances
+        .iter()
+        .filter(|(coord, _)| !visited.contains(coord))
+        .min_by_key(|(_, distance)| **distance)
+        .map(|(coord, distance)| (coord.clone(), *distance))
+}
+
+pub struct Solution {}
+
+impl Solution {
+    pub fn new() -> Solution {
+        Solution {}
+    }
+}
+
+impl Assignment for Solution {
+    type Input = Grid;
+    type Output = Output;
+    type Params = ();
+
+    fn parse_input(&self, input: &str) -> Result<Self::Input, SolveError> {
+        Grid::parse(input)
+    }
+
+    fn silver(
+        &self,
+        grid: &Self::Input,
+        _params: &Self::Params,
+    ) -> Result<Self::Output, SolveError> {
+        let mut distances = HashMap::new();
+        let mut visited = HashSet::new();
+        distances.insert(grid.start.clone(), 0);
+
+        while let Some((to_check, distance)) = closest_unvisited(&distances, &visited) {
+            for coord in grid.possible_next(&to_check) {
+                if distances
+                    .get(&coord)
+                    .is_none_or(|known| *known > distance + 1)
+                {
+                    distances.insert(coord, distance + 1);
+                }
+            }
+
+            visited.insert(to_check);
+        }
+
+        let distance = distances
+            .get(&grid.end)
+            .ok_or_else(|| SolveError::new("The start can't be reached from the top"))?;
+        Ok((*distance).into())
+    }
+
+    fn gold(&self, grid: &Self::Input, _params: &Self::Params) -> Result<Self::Output, SolveError> {
+        let mut distances = HashMap::new();
+        let mut visited = HashSet::new();
+        distances.insert(grid.start.clone(), 0);
+
+        while let Some((to_check, distance)) = closest_unvisited(&distances, &visited) {
+            for coord in grid.possible_next(&to_check) {
+                if distances
+                    .get(&coord)
+                    .is_none_or(|known| *known > distance + 1)
+                {
+                    distances.insert(coord, distance + 1);
+                }
+            }
+
+            visited.insert(to_check);
+        }
+
+        let distance = distances
+            .into_iter()
+            .filter(|(coord, _)| grid.elevations[coord.y][coord.x] == 26)
+            .map(|(_, d)| d)
+            .min()
+            .ok_or_else(|| SolveError::new("No square at elevation a can be reached"))?;
+        Ok(distance.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::assignment_tests;
+
+    static TEST_INPUT: [(&str, i32, i32); 3] = [
+        ("Sabqponm\nabcryxxl\naccszExk\nacctuvwj\nabdefghi", 31, 29),
+        ("SEzyxwv\napqrstu\nbonmlkj\ncdefghi", 27, 26),
+        (
+            "xwvsron\nyyutqpm\nSEayxwv\nbpqrstu\nbonmlkj\ncdefghi",
+            33,
+            33,
+        ),
+    ];
+
+    assignment_tests! {
+        examples: TEST_INPUT;
+    }
+
+    #[test]
+    fn test_invalid_grid() {
+        let sol = Solution::new();
+        assert!(sol.parse_input("Sbc\nab\nabE").is_err());
+        assert!(sol.parse_input("Sbc\nabc").is_err());
+        assert!(sol.parse_input("").is_err());
+    }
+}
@@ -0,0 +1,217 @@
+use std::{cmp::Ordering, collections::HashMap};
+
+use regex::Regex;
+
+use crate::{
+    error::SolveError,
+    parse::{self, Line},
+    Assignment, Output,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PacketValue {
+    Array(Vec<PacketValue>),
+    Int(u8),
+}
+
+impl PacketValue {
+    /// Parse a packet, pointing at the offending character when it is malformed
+    fn parse(line: Line) -> Result<PacketValue, SolveError> {
+        let mut open = Vec::new();
+        for (index, c) in line.text.char_indices() {
+            let text = &line.text[index..index + c.len_utf8()];
+            match c {
+                '[' => open.push(text),
+                ']' if open.pop().is_none() => {
+                    return Err(line
+                        .error(text, "This ']' closes a list that was never opened")
+                        .with_hint("the brackets of a packet should balance"))
+                }
+                ']' | ',' | '0'..='9' => (),
+                _ => {
+                    return Err(line
+                        .error(text, "Unexpected character in a packet")
+                        .with_hint("packets only contain brackets, commas and numbers"))
+                }
+            }
+        }
+        if let Some(unclosed) = open.pop() {
+            return Err(line
+                .error(unclosed, "This '[' is never closed")
+                .with_hint("the brackets of a packet should balance, add a ']'"));
+        }
+
+        let list_finder = Regex::new(r"\[([^\[\]]*)\]").unwrap();
+        let mut cloned_str = line.text.trim().to_string();
+        let mut x: HashMap<String, PacketValue> = HashMap::new();
+        let mut x_index = 0;
+
+        while let Some(caps) = list_finder.captures(cloned_str.as_str()) {
+            let values = caps[1]
+                .split(',')
+                .map(|d| match d {
+                    "" => Ok(PacketValue::Array(Vec::new())),
+                    _ => match x.get(d) {
+                        Some(value) => Ok(value.to_owned()),
+                        None => Ok(PacketValue::Int(line.parse(d)?)),
+                    },
+                })
+                .collect::<Result<Vec<PacketValue>, SolveError>>()?;
+
+            let x_key = format!("x_{}", x_index);
+            x_index += 1;
+            x.insert(x_key.clone(), PacketValue::Array(values));
+            cloned_str = list_finder.replace(cloned_str.as_str(), x_key).into_owned();
+        }
+
+        x.remove(&cloned_str).ok_or_else(|| {
+            line.error(line.text, "A packet should be a single list")
+                .with_hint("wrap the packet in one pair of brackets")
+        })
+    }
+}
+
+impl std::str::FromStr for PacketValue {
+    type Err = SolveError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        PacketValue::parse(Line { number: 0, text: s })
+    }
+}
+
+impl PartialOrd for PacketValue {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for PacketValue {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // println!("Self: {:?}\nOther: {:?}\n", self, other);
+
+        match (self, other) {
+            (PacketValue::Array(left_a), PacketValue::Array(right_a)) => left_a.cmp(right_a),
+            (PacketValue::Array(_), PacketValue::Int(_)) => {
+                self.cmp(&PacketValue::Array(vec![other.clone()]))
+            }
+            (PacketValue::Int(_), PacketValue::Array(_)) => {
+                PacketValue::Array(vec![self.clone()]).cmp(other)
+            }
+            (PacketValue::Int(left_d), PacketValue::Int(right_d)) => left_d.cmp(right_d),
+        }
+    }
+}
+
+pub struct Solution {}
+
+impl Solution {
+    pub fn new() -> Solution {
+        Solution {}
+    }
+}
+
+impl Assignment for Solution {
+    type Input = Vec<(PacketValue, PacketValue)>;
+    type Output = Output;
+    type Params = ();
+
+    fn parse_input(&self, input: &str) -> Result<Self::Input, SolveError> {
+        let lines = parse::lines(input).collect::<Vec<_>>();
+        lines
+            .split(|line| line.text.is_empty())
+            .filter(|pair| !pair.is_empty())
+            .map(|pair| match pair {
+                [left, right] => Ok((PacketValue::parse(*left)?, PacketValue::parse(*right)?)),
+                _ => Err(pair[0].error(pair[0].text, "Packets should come in pairs")),
+            })
+            .collect()
+    }
+
+    fn silver(
+        &self,
+        input: &Self::Input,
+        _params: &Self::Params,
+    ) -> Result<Self::Output, SolveError> {
+        Ok(input
+            .iter()
+            .enumerate()
+            .map(|(i, (left, right))| ((i + 1) as i32, left < right))
+            .filter(|(_, b)| *b)
+            .map(|(i, _)| i)
+            .sum::<i32>()
+            .into())
+    }
+
+    fn gold(
+        &self,
+        input: &Self::Input,
+        _params: &Self::Params,
+    ) -> Result<Self::Output, SolveError> {
+        let first_divider: PacketValue = "[[2]]".parse().unwrap();
+        let second_divider: PacketValue = "[[6]]".parse().unwrap();
+        let mut sorted_packets = input.iter().fold(Vec::new(), |mut total, (left, right)| {
+            total.push(left.to_owned());
+            total.push(right.to_owned());
+            total
+        });
+        sorted_packets.push(first_divider.clone());
+        sorted_packets.push(second_divider.clone());
+        sorted_packets.sort_by(PacketValue::cmp);
+
+        Ok(sorted_packets
+            .into_iter()
+            .enumerate()
+            .filter(|(_, packet)| packet == &first_divider || packet == &second_divider)
+            .map(|(d, _)| (d as i32) + 1)
+            .product::<i32>()
+            .into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::assignment_tests;
+
+    static TEST_INPUT: &str = "[1,1,3,1,1]
+[1,1,5,1,1]
+
+[[1],[2,3,4]]
+[[1],4]
+
+[9]
+[[8,7,6]]
+
+[[4,4],4,4]
+[[4,4],4,4,4]
+
+[7,7,7,7]
+[7,7,7]
+
+[]
+[3]
+
+[[[]]]
+[[]]
+
+[1,[2,[3,[4,[5,6,7]]]],8,9]
+[1,[2,[3,[4,[5,6,0]]]],8,9]";
+
+    assignment_tests! {
+        silver: TEST_INPUT => 13;
+        gold: TEST_INPUT => 140;
+    }
+
+    #[test]
+    fn test_unbalanced_brackets() {
+        let sol = Solution::new();
+        let err = sol.parse_input("[1,[2]\n[3]\n").err().unwrap();
+        let location = err.location.unwrap();
+
+        assert_eq!((location.line, location.column), (1, 1));
+        assert_eq!(err.message, "This '[' is never closed");
+
+        let err = sol.parse_input("[1]\n[3]]\n").err().unwrap();
+        assert_eq!(err.location.unwrap().column, 4);
+    }
+}
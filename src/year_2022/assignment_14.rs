@@ -1,6 +1,6 @@
 use std::fmt::Display;
 
-use crate::{Assignment, Output};
+use crate::{error::SolveError, parse, Assignment, Output};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 enum Item {
@@ -141,45 +141,46 @@ impl Solution {
 impl Assignment for Solution {
     type Input = Grid;
     type Output = Output;
-
-    fn parse_input(&self, input: &str) -> Option<Self::Input> {
-        let coords = input
-            .lines()
-            .flat_map(|line| {
-                let coords_row = line
-                    .split(" -> ")
-                    .map(|coord_str| {
-                        let (x, y) = coord_str.split_once(',').unwrap();
-                        (x.parse::<usize>().unwrap(), y.parse::<usize>().unwrap())
-                    })
-                    .collect::<Vec<(usize, usize)>>();
-
-                let mut resulting_coords = Vec::new();
-                resulting_coords.push(coords_row[0]);
-                for i in 1..coords_row.len() {
-                    let a = coords_row[i - 1];
-                    let b = coords_row[i];
-                    let x_a = a.0 as i32;
-                    let y_a = a.1 as i32;
-                    let x_b = b.0 as i32;
-                    let y_b = b.1 as i32;
-
-                    let d_x = (x_b - x_a).signum();
-                    let d_y = (y_b - y_a).signum();
-
-                    let x_range = std::cmp::max((x_b - x_a).abs(), 1);
-                    let y_range = std::cmp::max((y_b - y_a).abs(), 1);
-
-                    for j in 1..=x_range {
-                        for k in 1..=y_range {
-                            resulting_coords
-                                .push(((x_a + (j * d_x)) as usize, (y_a + (k * d_y)) as usize));
-                        }
+    type Params = ();
+
+    fn parse_input(&self, input: &str) -> Result<Self::Input, SolveError> {
+        let mut coords = Vec::new();
+        for line in parse::lines(input) {
+            let coords_row = line
+                .text
+                .split(" -> ")
+                .map(|coord_str| {
+                    let (x, y) = line.split(coord_str, ",")?;
+                    Ok((line.parse::<usize>(x)?, line.parse::<usize>(y)?))
+                })
+                .collect::<Result<Vec<(usize, usize)>, SolveError>>()?;
+
+            coords.push(coords_row[0]);
+            for i in 1..coords_row.len() {
+                let a = coords_row[i - 1];
+                let b = coords_row[i];
+                let x_a = a.0 as i32;
+                let y_a = a.1 as i32;
+                let x_b = b.0 as i32;
+                let y_b = b.1 as i32;
+
+                let d_x = (x_b - x_a).signum();
+                let d_y = (y_b - y_a).signum();
+
+                let x_range = std::cmp::max((x_b - x_a).abs(), 1);
+                let y_range = std::cmp::max((y_b - y_a).abs(), 1);
+
+                for j in 1..=x_range {
+                    for k in 1..=y_range {
+                        coords.push(((x_a + (j * d_x)) as usize, (y_a + (k * d_y)) as usize));
                     }
                 }
-                resulting_coords
-            })
-            .collect::<Vec<(usize, usize)>>();
+            }
+        }
+
+        if coords.is_empty() {
+            return Err(SolveError::new("There are no rock paths"));
+        }
 
         let mut min_x = usize::MAX;
         let mut max_x = usize::MIN;
@@ -198,21 +199,30 @@ impl Assignment for Solution {
             }
         }
 
-        Some(Grid::new(coords, min_x, min_y, max_x, max_y))
+        Ok(Grid::new(coords, min_x, min_y, max_x, max_y))
     }
 
-    fn silver(&self, grid: &Self::Input) -> Option<Self::Output> {
+    fn silver(
+        &self,
+        grid: &Self::Input,
+        _params: &Self::Params,
+    ) -> Result<Self::Output, SolveError> {
         let mut cloned_grid = grid.clone();
+        if cloned_grid.get(&500, &0).is_none() {
+            return Err(SolveError::new(
+                "The sand source at 500,0 is outside of the cave",
+            ));
+        }
         // println!("{}", cloned_grid);
 
         while cloned_grid.drop_rock(500, 0) {}
 
         // println!("{}", cloned_grid);
 
-        Some(cloned_grid.get_total_rocks().into())
+        Ok(cloned_grid.get_total_rocks().into())
     }
 
-    fn gold(&self, grid: &Self::Input) -> Option<Self::Output> {
+    fn gold(&self, grid: &Self::Input, _params: &Self::Params) -> Result<Self::Output, SolveError> {
         let cloned_grid = grid.clone();
         let mut bigger_grid = Grid::enlarge(cloned_grid);
         // println!("{}", bigger_grid);
@@ -221,30 +231,20 @@ impl Assignment for Solution {
 
         // println!("{}", bigger_grid);
 
-        Some(bigger_grid.get_total_rocks().into())
+        Ok(bigger_grid.get_total_rocks().into())
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::testing::assignment_tests;
 
     static TEST_INPUT: &str = "498,4 -> 498,6 -> 496,6
 503,4 -> 502,4 -> 502,9 -> 494,9";
 
-    #[test]
-    fn test_silver() {
-        let sol = Solution::new();
-        let input = sol.parse_input(TEST_INPUT);
-        let result = sol.silver(&input.unwrap()).unwrap();
-        assert_eq!(result, 24)
-    }
-
-    #[test]
-    fn test_gold() {
-        let sol = Solution::new();
-        let input = sol.parse_input(TEST_INPUT);
-        let result = sol.gold(&input.unwrap()).unwrap();
-        assert_eq!(result, 93)
+    assignment_tests! {
+        silver: TEST_INPUT => 24;
+        gold: TEST_INPUT => 93;
     }
 }
@@ -1,6 +1,6 @@
 use std::collections::{HashSet, VecDeque};
 
-use crate::{Assignment, Output};
+use crate::{error::SolveError, params::params, parse, Assignment, Output};
 
 #[derive(Debug, Eq, Hash, PartialEq)]
 enum SensorType {
@@ -94,6 +94,15 @@ fn merge_line_segments(mut line_segments: VecDeque<(i32, i32)>) -> VecDeque<(i32
     }
 }
 
+params! {
+    pub struct Params {
+        /// The row of which the positions without a beacon are counted
+        row: i32 = 2_000_000,
+        /// The largest coordinate at which the distress beacon can be
+        bound: i32 = 4_000_000,
+    }
+}
+
 pub struct Solution {}
 
 impl Solution {
@@ -103,33 +112,48 @@ impl Solution {
 }
 
 impl Assignment for Solution {
-    type Input = (Vec<Sensor>, Vec<Sensor>, bool);
+    type Input = (Vec<Sensor>, Vec<Sensor>);
     type Output = Output;
+    type Params = Params;
 
-    fn parse_input(&self, input: &str) -> Option<Self::Input> {
+    fn parse_input(&self, input: &str) -> Result<Self::Input, SolveError> {
         let digit_finder = regex::Regex::new(r"Sensor at x=(?P<s_x>-?\d+), y=(?P<s_y>-?\d+): closest beacon is at x=(?P<b_x>-?\d+), y=(?P<b_y>-?\d+)").unwrap();
         let mut sensors: Vec<Sensor> = Vec::new();
         let mut beacons: HashSet<Sensor> = HashSet::new();
 
-        for caps in digit_finder.captures_iter(input) {
-            let s_x = caps.name("s_x").unwrap().as_str().parse::<i32>().unwrap();
-            let s_y = caps.name("s_y").unwrap().as_str().parse::<i32>().unwrap();
-            let b_x = caps.name("b_x").unwrap().as_str().parse::<i32>().unwrap();
-            let b_y = caps.name("b_y").unwrap().as_str().parse::<i32>().unwrap();
+        for line in parse::lines(input) {
+            let caps = digit_finder.captures(line.text).ok_or_else(|| {
+                line.error(
+                    line.text,
+                    "Expected 'Sensor at x=.., y=..: closest beacon is at x=.., y=..'",
+                )
+            })?;
+            let coordinate = |name: &str| line.parse::<i32>(&caps[name]);
+            let s_x = coordinate("s_x")?;
+            let s_y = coordinate("s_y")?;
+            let b_x = coordinate("b_x")?;
+            let b_y = coordinate("b_y")?;
 
             sensors.push(Sensor::new((s_x, s_y), SensorType::Sensor, (b_x, b_y)));
             beacons.insert(Sensor::new((b_x, b_y), SensorType::Beacon, (b_x, b_y)));
         }
 
-        Some((sensors, beacons.into_iter().collect(), false))
+        if sensors.is_empty() {
+            return Err(SolveError::new("There are no sensors"));
+        }
+        Ok((sensors, beacons.into_iter().collect()))
     }
 
-    fn silver(&self, (sensors, beacons, test_flag): &Self::Input) -> Option<Self::Output> {
+    fn silver(
+        &self,
+        (sensors, beacons): &Self::Input,
+        params: &Self::Params,
+    ) -> Result<Self::Output, SolveError> {
         let max_x = sensors.iter().map(|sensor| sensor.max_x()).max().unwrap();
         let min_x = sensors.iter().map(|sensor| sensor.min_x()).min().unwrap();
         let mut count = 0;
 
-        let y = if *test_flag { 10 } else { 2_000_000 };
+        let y = params.row;
 
         for i in min_x..=max_x {
             if sensors.iter().any(|sensor| sensor.can_be_beacon((i, y)))
@@ -140,11 +164,15 @@ impl Assignment for Solution {
                 count += 1;
             }
         }
-        Some((count).into())
+        Ok((count).into())
     }
 
-    fn gold(&self, (sensors, _, test_flag): &Self::Input) -> Option<Self::Output> {
-        let max = if *test_flag { 20 } else { 4_000_000 };
+    fn gold(
+        &self,
+        (sensors, _): &Self::Input,
+        params: &Self::Params,
+    ) -> Result<Self::Output, SolveError> {
+        let max = params.bound;
         let mut x: i128 = 0;
         let mut y: i128 = 0;
 
@@ -163,13 +191,16 @@ impl Assignment for Solution {
             }
         }
 
-        Some((x * 4_000_000 + y).into())
+        Ok((x * 4_000_000 + y).into())
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::testing::assignment_tests;
+
+    const EXAMPLE_PARAMS: Params = Params { row: 10, bound: 20 };
 
     static TEST_INPUT: &str = "Sensor at x=2, y=18: closest beacon is at x=-2, y=15
 Sensor at x=9, y=16: closest beacon is at x=10, y=16
@@ -186,21 +217,9 @@ Sensor at x=16, y=7: closest beacon is at x=15, y=3
 Sensor at x=14, y=3: closest beacon is at x=15, y=3
 Sensor at x=20, y=1: closest beacon is at x=15, y=3";
 
-    #[test]
-    fn test_silver() {
-        let sol = Solution::new();
-        let input = sol.parse_input(TEST_INPUT);
-        let (sensors, beacons, _) = input.unwrap();
-        let result = sol.silver(&(sensors, beacons, true)).unwrap();
-        assert_eq!(result, 26)
-    }
-
-    #[test]
-    fn test_gold() {
-        let sol = Solution::new();
-        let input = sol.parse_input(TEST_INPUT);
-        let (sensors, beacons, _) = input.unwrap();
-        let result = sol.gold(&(sensors, beacons, true)).unwrap();
-        assert_eq!(result, 56_000_011)
+    assignment_tests! {
+        params: EXAMPLE_PARAMS;
+        silver: TEST_INPUT => 26;
+        gold: TEST_INPUT => 56_000_011;
     }
 }
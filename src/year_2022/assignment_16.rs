@@ -1,6 +1,10 @@
 use std::collections::{HashMap, HashSet};
 
-use crate::{Assignment, Output};
+use crate::{
+    error::SolveError,
+    parse::{self, Line},
+    Assignment, Output,
+};
 
 #[derive(Debug, Clone)]
 pub struct Room {
@@ -9,41 +13,35 @@ pub struct Room {
     flow_rate: i32,
 }
 
-impl Room {}
+/// `parse_input` rejects tunnels to valves that don't exist, so every valve can be looked up
+const CHECKED_TUNNELS: &str = "parse_input checks that every tunnel leads to a valve";
 
-impl std::str::FromStr for Room {
-    type Err = String;
+impl Room {}
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
+impl Room {
+    fn parse(line: Line) -> Result<Room, SolveError> {
         let room_finder = regex::Regex::new(r"\b[A-Z]{2}\b").unwrap();
         let flow_finder = regex::Regex::new(r"\d+").unwrap();
 
-        let mut room_iter = room_finder.captures_iter(s);
+        let mut room_iter = room_finder.find_iter(line.text);
         let name = room_iter
             .next()
-            .unwrap()
-            .get(0)
-            .unwrap()
+            .ok_or_else(|| line.error(line.text, "Expected the name of a valve"))?
             .as_str()
             .to_string();
 
         let connected_rooms = room_iter
-            .map(|cap| cap.get(0).unwrap().as_str().to_string())
+            .map(|found| found.as_str().to_string())
             .collect::<Vec<String>>();
 
         let flow_rate = flow_finder
-            .captures(s)
-            .unwrap()
-            .get(0)
-            .unwrap()
-            .as_str()
-            .parse::<i32>()
-            .unwrap();
+            .find(line.text)
+            .ok_or_else(|| line.error(line.text, "Expected a flow rate"))?;
 
         Ok(Room {
             name,
             connected_rooms,
-            flow_rate,
+            flow_rate: line.parse(flow_rate.as_str())?,
         })
     }
 }
@@ -59,32 +57,45 @@ impl Solution {
 impl Assignment for Solution {
     type Input = HashMap<String, Room>;
     type Output = Output;
+    type Params = ();
 
-    fn parse_input(&self, input: &str) -> Option<Self::Input> {
+    fn parse_input(&self, input: &str) -> Result<Self::Input, SolveError> {
         let mut map = HashMap::new();
-        let rooms = input
-            .lines()
-            .into_iter()
-            .map(|s| s.parse::<Room>().unwrap())
-            .collect::<Vec<Room>>();
+        let lines = parse::lines(input).collect::<Vec<_>>();
+        let rooms = lines
+            .iter()
+            .map(|line| Room::parse(*line))
+            .collect::<Result<Vec<Room>, _>>()?;
+
+        for (line, room) in lines.iter().zip(&rooms) {
+            if let Some(unknown) = room
+                .connected_rooms
+                .iter()
+                .find(|name| !rooms.iter().any(|room| &room.name == *name))
+            {
+                return Err(line.error(unknown, "This valve does not exist"));
+            }
+        }
 
         for room in rooms {
             map.insert(room.name.clone(), room);
         }
 
-        Some(map)
+        if !map.contains_key("AA") {
+            return Err(SolveError::new("There is no valve AA to start at"));
+        }
+        Ok(map)
     }
 
-    fn silver(&self, rooms: &Self::Input) -> Option<Self::Output> {
+    fn silver(
+        &self,
+        rooms: &Self::Input,
+        _params: &Self::Params,
+    ) -> Result<Self::Output, SolveError> {
         let mut paths = HashSet::new();
         paths.insert((String::from("AA"), Vec::new(), 0, 0));
 
-        let max_flow_rate = rooms
-            .iter()
-            .max_by(|a, b| a.1.flow_rate.cmp(&b.1.flow_rate))
-            .unwrap()
-            .1
-            .flow_rate;
+        let max_flow_rate = rooms.values().map(|room| room.flow_rate).max().unwrap_or(0);
 
         for _ in 0..30 {
             // println!("Minute: {}\nPaths: {:?}\n", i, paths);
@@ -92,7 +103,7 @@ impl Assignment for Solution {
                 .iter()
                 .flat_map(|(room_id, opened, total_flow, released_pressure)| {
                     let mut branched_paths = HashSet::new();
-                    let room = rooms.get(room_id).unwrap();
+                    let room = rooms.get(room_id).expect(CHECKED_TUNNELS);
                     if !opened.contains(room_id) && room.flow_rate > 0 {
                         let mut cloned_opened = opened.clone();
                         cloned_opened.push(room_id.clone());
@@ -116,7 +127,7 @@ impl Assignment for Solution {
                 // .filter(|path| i < 5 || path.2 > 0)
                 .collect::<HashSet<(String, Vec<String>, i32, i32)>>();
 
-            let max_released = new_paths.iter().max_by(|a, b| a.3.cmp(&b.3)).unwrap().3;
+            let max_released = new_paths.iter().map(|path| path.3).max().unwrap_or(0);
 
             new_paths = new_paths
                 .into_iter()
@@ -126,21 +137,20 @@ impl Assignment for Solution {
             paths = new_paths;
         }
 
-        let max_released = paths.iter().max_by(|a, b| a.3.cmp(&b.3)).unwrap().3;
+        let max_released = paths.iter().map(|path| path.3).max().unwrap_or(0);
 
-        Some(max_released.into())
+        Ok(max_released.into())
     }
 
-    fn gold(&self, rooms: &Self::Input) -> Option<Self::Output> {
+    fn gold(
+        &self,
+        rooms: &Self::Input,
+        _params: &Self::Params,
+    ) -> Result<Self::Output, SolveError> {
         let mut paths = HashSet::new();
         paths.insert(((String::from("AA"), String::from("AA")), Vec::new(), 0, 0));
 
-        let max_flow_rate = rooms
-            .iter()
-            .max_by(|a, b| a.1.flow_rate.cmp(&b.1.flow_rate))
-            .unwrap()
-            .1
-            .flow_rate;
+        let max_flow_rate = rooms.values().map(|room| room.flow_rate).max().unwrap_or(0);
 
         for _ in 0..26 {
             // println!("Minute: {}\nPaths: {:?}\n", i, paths);
@@ -149,8 +159,8 @@ impl Assignment for Solution {
                 .flat_map(
                     |((room_id_me, room_id_elephant), opened, total_flow, released_pressure)| {
                         let mut branched_paths = HashSet::new();
-                        let room_me = rooms.get(room_id_me).unwrap();
-                        let room_elephant = rooms.get(room_id_elephant).unwrap();
+                        let room_me = rooms.get(room_id_me).expect(CHECKED_TUNNELS);
+                        let room_elephant = rooms.get(room_id_elephant).expect(CHECKED_TUNNELS);
 
                         let mut new_rooms_me = room_me.connected_rooms.clone();
                         let mut new_rooms_elephant = room_elephant.connected_rooms.clone();
@@ -192,7 +202,7 @@ impl Assignment for Solution {
                 // .filter(|path| i < 5 || path.2 > 0)
                 .collect::<HashSet<((String, String), Vec<String>, i32, i32)>>();
 
-            let max_released = new_paths.iter().max_by(|a, b| a.3.cmp(&b.3)).unwrap().3;
+            let max_released = new_paths.iter().map(|path| path.3).max().unwrap_or(0);
 
             new_paths = new_paths
                 .into_iter()
@@ -202,15 +212,16 @@ impl Assignment for Solution {
             paths = new_paths;
         }
 
-        let max_released = paths.iter().max_by(|a, b| a.3.cmp(&b.3)).unwrap().3;
+        let max_released = paths.iter().map(|path| path.3).max().unwrap_or(0);
 
-        Some(max_released.into())
+        Ok(max_released.into())
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::testing::assignment_tests;
 
     static TEST_INPUT: &str = "Valve AA has flow rate=0; tunnels lead to valves DD, II, BB
 Valve BB has flow rate=13; tunnels lead to valves CC, AA
@@ -223,19 +234,18 @@ Valve HH has flow rate=22; tunnel leads to valve GG
 Valve II has flow rate=0; tunnels lead to valves AA, JJ
 Valve JJ has flow rate=21; tunnel leads to valve II";
 
-    #[test]
-    fn test_silver() {
-        let sol = Solution::new();
-        let input = sol.parse_input(TEST_INPUT);
-        let result = sol.silver(&input.unwrap()).unwrap();
-        assert_eq!(result, 1651)
+    assignment_tests! {
+        silver: TEST_INPUT => 1651;
+        gold: TEST_INPUT => 1707;
     }
 
     #[test]
-    fn test_gold() {
+    fn test_without_flow() {
         let sol = Solution::new();
-        let input = sol.parse_input(TEST_INPUT);
-        let result = sol.gold(&input.unwrap()).unwrap();
-        assert_eq!(result, 1707)
+        let input = sol
+            .parse_input("Valve AA has flow rate=0; tunnels lead to valves BB\nValve BB has flow rate=0; tunnels lead to valves AA")
+            .unwrap();
+        assert_eq!(sol.silver(&input, &()).unwrap(), 0);
+        assert_eq!(sol.gold(&input, &()).unwrap(), 0);
     }
 }
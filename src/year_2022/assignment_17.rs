@@ -1,4 +1,4 @@
-use crate::{Assignment, Output};
+use crate::{error::SolveError, parse, Assignment, Output};
 
 #[derive(Debug, PartialEq, Eq)]
 pub enum Direction {
@@ -24,16 +24,6 @@ static FALL_ORDER: [Shape; 5] = [
     Shape::Block,
 ];
 
-impl std::convert::From<char> for Direction {
-    fn from(c: char) -> Self {
-        match c {
-            '<' => Direction::Left,
-            '>' => Direction::Right,
-            _ => panic!("Couldn't parse {}", c),
-        }
-    }
-}
-
 #[derive(Debug, Clone, PartialEq, Eq)]
 enum FieldType {
     Empty,
@@ -269,7 +259,7 @@ impl std::fmt::Display for PlayField {
     }
 }
 
-fn play_tetris(input: &Vec<Direction>, output_size: i64) -> Option<Output> {
+fn play_tetris(input: &[Direction], output_size: i64) -> Result<Output, SolveError> {
     let mut field = PlayField::new();
     let mut rocks_fallen = 0;
     let mut direction_index = 0;
@@ -313,7 +303,7 @@ fn play_tetris(input: &Vec<Direction>, output_size: i64) -> Option<Output> {
 
     // println!("{}", field);
 
-    Some((add_to_result + field.heighest_rock() as i64).into())
+    Ok((add_to_result + field.heighest_rock() as i64).into())
 }
 
 pub struct Solution {}
@@ -327,12 +317,34 @@ impl Solution {
 impl Assignment for Solution {
     type Input = Vec<Direction>;
     type Output = Output;
+    type Params = ();
+
+    fn parse_input(&self, input: &str) -> Result<Self::Input, SolveError> {
+        let mut directions = Vec::new();
+        for line in parse::lines(input.trim()) {
+            for (index, c) in line.text.char_indices() {
+                directions.push(match c {
+                    '<' => Direction::Left,
+                    '>' => Direction::Right,
+                    _ => {
+                        let text = &line.text[index..index + c.len_utf8()];
+                        return Err(line.error(text, "Unknown jet, expected < or >"));
+                    }
+                });
+            }
+        }
 
-    fn parse_input(&self, input: &str) -> Option<Self::Input> {
-        Some(input.trim().chars().map(Direction::from).collect())
+        if directions.is_empty() {
+            return Err(SolveError::new("There is no jet pattern"));
+        }
+        Ok(directions)
     }
 
-    fn silver(&self, input: &Self::Input) -> Option<Self::Output> {
+    fn silver(
+        &self,
+        input: &Self::Input,
+        _params: &Self::Params,
+    ) -> Result<Self::Output, SolveError> {
         play_tetris(input, 2022)
 
         // let mut field = PlayField::new();
@@ -368,7 +380,11 @@ impl Assignment for Solution {
         // Some((field.heighest_rock() as i64).into())
     }
 
-    fn gold(&self, input: &Self::Input) -> Option<Self::Output> {
+    fn gold(
+        &self,
+        input: &Self::Input,
+        _params: &Self::Params,
+    ) -> Result<Self::Output, SolveError> {
         play_tetris(input, 1_000_000_000_000)
     }
 }
@@ -376,23 +392,12 @@ impl Assignment for Solution {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::testing::assignment_tests;
 
     static TEST_INPUT: &str = ">>><<><>><<<>><>>><<<>>><<<><<<>><>><<>>";
 
-    #[test]
-    fn test_silver() {
-        let sol = Solution::new();
-        let input = sol.parse_input(TEST_INPUT);
-        let result = sol.silver(&input.unwrap()).unwrap();
-        assert_eq!(result, 3068)
-    }
-
-    #[test]
-    fn test_gold() {
-        let sol = Solution::new();
-        let input = sol.parse_input(TEST_INPUT);
-        let result = sol.gold(&input.unwrap()).unwrap();
-        let should_be: i64 = 1_514_285_714_288;
-        assert_eq!(result, should_be)
+    assignment_tests! {
+        silver: TEST_INPUT => 3068;
+        gold: TEST_INPUT => 1_514_285_714_288i64;
     }
 }
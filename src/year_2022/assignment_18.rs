@@ -1,6 +1,10 @@
 use std::collections::HashSet;
 
-use crate::{Assignment, Output};
+use crate::{
+    error::SolveError,
+    parse::{self, Line},
+    Assignment, Output,
+};
 
 #[derive(Debug, Clone, Hash, PartialEq, Eq)]
 pub struct Coord {
@@ -31,17 +35,16 @@ impl Coord {
     }
 }
 
-impl std::str::FromStr for Coord {
-    type Err = String;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut coords = s.split(',').map(|d| d.parse::<i32>().unwrap());
-
-        let x = coords.next().unwrap();
-        let y = coords.next().unwrap();
-        let z = coords.next().unwrap();
-
-        Ok(Coord { x, y, z })
+impl Coord {
+    fn parse(line: Line) -> Result<Coord, SolveError> {
+        let (x, rest) = line.split_once(",")?;
+        let (y, z) = line.split(rest, ",")?;
+
+        Ok(Coord {
+            x: line.parse(x)?,
+            y: line.parse(y)?,
+            z: line.parse(z)?,
+        })
     }
 }
 
@@ -56,27 +59,41 @@ impl Solution {
 impl Assignment for Solution {
     type Input = Vec<Coord>;
     type Output = Output;
+    type Params = ();
+
+    fn parse_input(&self, input: &str) -> Result<Self::Input, SolveError> {
+        let cubes = parse::lines(input)
+            .map(Coord::parse)
+            .collect::<Result<Vec<_>, _>>()?;
 
-    fn parse_input(&self, input: &str) -> Option<Self::Input> {
-        Some(input.lines().map(|line| line.parse().unwrap()).collect())
+        if cubes.is_empty() {
+            return Err(SolveError::new("There are no cubes"));
+        }
+        Ok(cubes)
     }
 
-    fn silver(&self, input: &Self::Input) -> Option<Self::Output> {
-        Some(
-            input
-                .iter()
-                .map(|coord| {
-                    6 - input
-                        .iter()
-                        .filter(|other| coord.is_adjacent(other))
-                        .count() as i32
-                })
-                .sum::<i32>()
-                .into(),
-        )
+    fn silver(
+        &self,
+        input: &Self::Input,
+        _params: &Self::Params,
+    ) -> Result<Self::Output, SolveError> {
+        Ok(input
+            .iter()
+            .map(|coord| {
+                6 - input
+                    .iter()
+                    .filter(|other| coord.is_adjacent(other))
+                    .count() as i32
+            })
+            .sum::<i32>()
+            .into())
     }
 
-    fn gold(&self, input: &Self::Input) -> Option<Self::Output> {
+    fn gold(
+        &self,
+        input: &Self::Input,
+        _params: &Self::Params,
+    ) -> Result<Self::Output, SolveError> {
         fn inner(
             to_check: Coord,
             checking_now: &mut Vec<Coord>,
@@ -154,9 +171,22 @@ impl Assignment for Solution {
             )
         }
 
-        let max_x = input.iter().map(|coord| coord.x).max().unwrap();
-        let max_y = input.iter().map(|coord| coord.y).max().unwrap();
-        let max_z = input.iter().map(|coord| coord.z).max().unwrap();
+        let no_cubes = || SolveError::new("There are no cubes");
+        let max_x = input
+            .iter()
+            .map(|coord| coord.x)
+            .max()
+            .ok_or_else(no_cubes)?;
+        let max_y = input
+            .iter()
+            .map(|coord| coord.y)
+            .max()
+            .ok_or_else(no_cubes)?;
+        let max_z = input
+            .iter()
+            .map(|coord| coord.z)
+            .max()
+            .ok_or_else(no_cubes)?;
 
         let mut visited = Vec::new();
         let mut encapsulated = HashSet::new();
@@ -204,33 +234,18 @@ impl Assignment for Solution {
             })
             .sum::<i32>();
 
-        Some((lava_edges - bubble_edges).into())
+        Ok((lava_edges - bubble_edges).into())
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::testing::assignment_tests;
 
     static TEST_INPUT: [(&str, i32, i32); 2] = [("2,2,2\n1,2,2\n3,2,2\n2,1,2\n2,3,2\n2,2,1\n2,2,3\n2,2,4\n2,2,6\n1,2,5\n3,2,5\n2,1,5\n2,3,5", 64, 58), ("1,1,1\n2,1,1\n3,1,1\n4,1,1\n5,1,1\n6,1,1\n1,2,1\n2,2,1\n3,2,1\n4,2,1\n5,2,1\n6,2,1\n1,3,1\n2,3,1\n3,3,1\n4,3,1\n5,3,1\n6,3,1\n1,1,2\n2,1,2\n3,1,2\n4,1,2\n5,1,2\n6,1,2\n1,2,2\n6,2,2\n1,3,2\n2,3,2\n3,3,2\n4,3,2\n5,3,2\n6,3,2\n1,1,3\n2,1,3\n3,1,3\n4,1,3\n5,1,3\n6,1,3\n1,2,3\n2,2,3\n3,2,3\n4,2,3\n5,2,3\n6,2,3\n1,3,3\n2,3,3\n3,3,3\n4,3,3\n5,3,3\n6,3,3", 108, 90)];
 
-    #[test]
-    fn test_silver() {
-        let sol = Solution::new();
-        for (raw, expected, _) in TEST_INPUT {
-            let input = sol.parse_input(raw);
-            let result = sol.silver(&input.unwrap()).unwrap();
-            assert_eq!(result, expected)
-        }
-    }
-
-    #[test]
-    fn test_gold() {
-        let sol = Solution::new();
-        for (raw, _, expected) in TEST_INPUT {
-            let input = sol.parse_input(raw);
-            let result = sol.gold(&input.unwrap()).unwrap();
-            assert_eq!(result, expected)
-        }
+    assignment_tests! {
+        examples: TEST_INPUT;
     }
 }
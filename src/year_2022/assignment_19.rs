@@ -1,4 +1,8 @@
-use crate::{Assignment, Output};
+use crate::{
+    error::SolveError,
+    parse::{self, Line},
+    Assignment, Output,
+};
 
 #[derive(Debug, Clone, Copy)]
 enum Robot {
@@ -44,77 +48,30 @@ impl Blueprint {
     }
 }
 
-impl std::str::FromStr for Blueprint {
-    type Err = String;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
+impl Blueprint {
+    fn parse(line: Line) -> Result<Blueprint, SolveError> {
         let digit_finder = regex::Regex::new(r"\d+").unwrap();
-        let mut caps = digit_finder.captures_iter(s);
+        let numbers = digit_finder
+            .find_iter(line.text)
+            .map(|found| line.parse::<i32>(found.as_str()))
+            .collect::<Result<Vec<i32>, _>>()?;
 
-        let id = caps
-            .next()
-            .unwrap()
-            .get(0)
-            .unwrap()
-            .as_str()
-            .parse::<i32>()
-            .unwrap();
-        let ore_ore = caps
-            .next()
-            .unwrap()
-            .get(0)
-            .unwrap()
-            .as_str()
-            .parse::<i32>()
-            .unwrap();
-        let clay_ore = caps
-            .next()
-            .unwrap()
-            .get(0)
-            .unwrap()
-            .as_str()
-            .parse::<i32>()
-            .unwrap();
-        let obsidian_ore = caps
-            .next()
-            .unwrap()
-            .get(0)
-            .unwrap()
-            .as_str()
-            .parse::<i32>()
-            .unwrap();
-        let obsidian_clay = caps
-            .next()
-            .unwrap()
-            .get(0)
-            .unwrap()
-            .as_str()
-            .parse::<i32>()
-            .unwrap();
-        let geode_ore = caps
-            .next()
-            .unwrap()
-            .get(0)
-            .unwrap()
-            .as_str()
-            .parse::<i32>()
-            .unwrap();
-        let geode_obsidian = caps
-            .next()
-            .unwrap()
-            .get(0)
-            .unwrap()
-            .as_str()
-            .parse::<i32>()
-            .unwrap();
-
-        Ok(Blueprint {
-            id,
-            ore: Robot::Ore(ore_ore),
-            clay: Robot::Clay(clay_ore),
-            obsidian: Robot::Obsidian(obsidian_ore, obsidian_clay),
-            geode: Robot::Geode(geode_ore, geode_obsidian),
-        })
+        if let [id, ore_ore, clay_ore, obsidian_ore, obsidian_clay, geode_ore, geode_obsidian] =
+            numbers[..]
+        {
+            Ok(Blueprint {
+                id,
+                ore: Robot::Ore(ore_ore),
+                clay: Robot::Clay(clay_ore),
+                obsidian: Robot::Obsidian(obsidian_ore, obsidian_clay),
+                geode: Robot::Geode(geode_ore, geode_obsidian),
+            })
+        } else {
+            Err(line.error(
+                line.text,
+                format!("Expected 7 numbers in a blueprint, found {}", numbers.len()),
+            ))
+        }
     }
 }
 
@@ -277,55 +234,53 @@ impl Solution {
 impl Assignment for Solution {
     type Input = Vec<Blueprint>;
     type Output = Output;
+    type Params = ();
 
-    fn parse_input(&self, input: &str) -> Option<Self::Input> {
-        Some(input.lines().map(|s| s.parse().unwrap()).collect())
+    fn parse_input(&self, input: &str) -> Result<Self::Input, SolveError> {
+        parse::lines(input)
+            .filter(|line| !line.text.trim().is_empty())
+            .map(Blueprint::parse)
+            .collect()
     }
 
-    fn silver(&self, input: &Self::Input) -> Option<Self::Output> {
+    fn silver(
+        &self,
+        input: &Self::Input,
+        _params: &Self::Params,
+    ) -> Result<Self::Output, SolveError> {
         let game_rounds = 24;
-        Some(
-            input
-                .iter()
-                .map(|blueprint| check_blueprint(blueprint, game_rounds) * blueprint.id)
-                .sum::<i32>()
-                .into(),
-        )
+        Ok(input
+            .iter()
+            .map(|blueprint| check_blueprint(blueprint, game_rounds) * blueprint.id)
+            .sum::<i32>()
+            .into())
     }
 
-    fn gold(&self, input: &Self::Input) -> Option<Self::Output> {
+    fn gold(
+        &self,
+        input: &Self::Input,
+        _params: &Self::Params,
+    ) -> Result<Self::Output, SolveError> {
         let game_rounds = 32;
-        Some(
-            input
-                .iter()
-                .take(3)
-                .map(|blueprint| check_blueprint(blueprint, game_rounds))
-                .product::<i32>()
-                .into(),
-        )
+        Ok(input
+            .iter()
+            .take(3)
+            .map(|blueprint| check_blueprint(blueprint, game_rounds))
+            .product::<i32>()
+            .into())
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::testing::assignment_tests;
 
     static TEST_INPUT: &str = "Blueprint 1: Each ore robot costs 4 ore. Each clay robot costs 2 ore. Each obsidian robot costs 3 ore and 14 clay. Each geode robot costs 2 ore and 7 obsidian.
 Blueprint 2: Each ore robot costs 2 ore. Each clay robot costs 3 ore. Each obsidian robot costs 3 ore and 8 clay. Each geode robot costs 3 ore and 12 obsidian.";
 
-    #[test]
-    fn test_silver() {
-        let sol = Solution::new();
-        let input = sol.parse_input(TEST_INPUT);
-        let result = sol.silver(&input.unwrap()).unwrap();
-        assert_eq!(result, 33)
-    }
-
-    #[test]
-    fn test_gold() {
-        let sol = Solution::new();
-        let input = sol.parse_input(TEST_INPUT);
-        let result = sol.gold(&input.unwrap()).unwrap();
-        assert_eq!(result, 3472)
+    assignment_tests! {
+        silver: TEST_INPUT => 33;
+        gold: TEST_INPUT => 3472;
     }
 }
@@ -1,4 +1,4 @@
-use crate::{Assignment, Output};
+use crate::{error::SolveError, parse, Assignment, Output};
 
 pub enum Played {
     Rock,
@@ -68,78 +68,75 @@ fn get_played(first: &Played, outcome: &Outcome) -> Played {
 impl Assignment for Solution {
     type Input = Vec<(Played, Played, Outcome)>;
     type Output = Output;
+    type Params = ();
 
-    fn parse_input(&self, input: &str) -> Option<Self::Input> {
+    fn parse_input(&self, input: &str) -> Result<Self::Input, SolveError> {
         let mut result = Vec::new();
-        for line in input.lines() {
-            let (first, second) = line
-                .split_once(' ')
-                .unwrap_or_else(|| panic!("Could not split line {}", line));
+        for line in parse::lines(input) {
+            let (first, second) = line.split_once(" ")?;
 
             let first_played = match first {
                 "A" => Played::Rock,
                 "B" => Played::Paper,
                 "C" => Played::Scissors,
-                s => panic!("Unknown symbol {s}"),
+                s => return Err(line.error(s, "Unknown symbol, expected A, B or C")),
             };
-            let second_played = match second {
-                "X" => Played::Rock,
-                "Y" => Played::Paper,
-                "Z" => Played::Scissors,
-                s => panic!("Unknown symbol {s}"),
-            };
-            let out = match second {
-                "X" => Outcome::Loss,
-                "Y" => Outcome::Draw,
-                "Z" => Outcome::Win,
-                s => panic!("Unknown symbol {s}"),
+            let (second_played, out) = match second {
+                "X" => (Played::Rock, Outcome::Loss),
+                "Y" => (Played::Paper, Outcome::Draw),
+                "Z" => (Played::Scissors, Outcome::Win),
+                s => return Err(line.error(s, "Unknown symbol, expected X, Y or Z")),
             };
 
             result.push((first_played, second_played, out));
         }
-        Some(result)
+        Ok(result)
     }
 
-    fn silver(&self, input: &Self::Input) -> Option<Self::Output> {
-        Some(
-            input
-                .iter()
-                .map(|(a, b, _outcome)| translate_score(b, &get_outcome(a, b)))
-                .sum::<i32>()
-                .into(),
-        )
+    fn silver(
+        &self,
+        input: &Self::Input,
+        _params: &Self::Params,
+    ) -> Result<Self::Output, SolveError> {
+        Ok(input
+            .iter()
+            .map(|(a, b, _outcome)| translate_score(b, &get_outcome(a, b)))
+            .sum::<i32>()
+            .into())
     }
 
-    fn gold(&self, input: &Self::Input) -> Option<Self::Output> {
-        Some(
-            input
-                .iter()
-                .map(|(a, _b, outcome)| translate_score(&get_played(a, outcome), outcome))
-                .sum::<i32>()
-                .into(),
-        )
+    fn gold(
+        &self,
+        input: &Self::Input,
+        _params: &Self::Params,
+    ) -> Result<Self::Output, SolveError> {
+        Ok(input
+            .iter()
+            .map(|(a, _b, outcome)| translate_score(&get_played(a, outcome), outcome))
+            .sum::<i32>()
+            .into())
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::testing::assignment_tests;
 
     static TEST_INPUT: &str = "A Y\nB X\nC Z\n";
 
-    #[test]
-    fn test_silver() {
-        let sol = Solution::new();
-        let input = sol.parse_input(TEST_INPUT);
-        let result = sol.silver(&input.unwrap()).unwrap();
-        assert_eq!(result, 15)
+    assignment_tests! {
+        silver: TEST_INPUT => 15;
+        gold: TEST_INPUT => 12;
     }
 
     #[test]
-    fn test_gold() {
+    fn test_unknown_symbol() {
         let sol = Solution::new();
-        let input = sol.parse_input(TEST_INPUT);
-        let result = sol.gold(&input.unwrap()).unwrap();
-        assert_eq!(result, 12)
+        let err = sol.parse_input("A Y\nB Q\n").err().unwrap();
+        let location = err.location.unwrap();
+
+        assert_eq!((location.line, location.column), (2, 3));
+        assert_eq!(location.text, "Q");
     }
 }
@@ -1,6 +1,6 @@
-use crate::{Assignment, Output};
+use crate::{error::SolveError, params::params, parse, Assignment, Output};
 
-fn get_wrap_around<T>(v: &Vec<T>, i: usize) -> Option<&T> {
+fn get_wrap_around<T>(v: &[T], i: usize) -> Option<&T> {
     v.get(i % v.len())
 }
 
@@ -23,6 +23,15 @@ fn mixing(v: &mut Vec<(usize, i64)>, i: usize) {
     v.insert(new_pos as usize, to_mix);
 }
 
+params! {
+    pub struct Params {
+        /// Every number is multiplied by this key before mixing
+        decryption_key: i64 = 811_589_153,
+        /// How often the whole file is mixed
+        mixes: usize = 10,
+    }
+}
+
 pub struct Solution {}
 
 impl Solution {
@@ -34,18 +43,27 @@ impl Solution {
 impl Assignment for Solution {
     type Input = Vec<(usize, i64)>;
     type Output = Output;
+    type Params = Params;
+
+    fn parse_input(&self, input: &str) -> Result<Self::Input, SolveError> {
+        let numbers = parse::lines(input)
+            .map(|line| line.parse::<i64>(line.text))
+            .collect::<Result<Vec<i64>, _>>()?;
 
-    fn parse_input(&self, input: &str) -> Option<Self::Input> {
-        Some(
-            input
-                .lines()
-                .map(|s_digit| s_digit.parse().unwrap())
-                .enumerate()
-                .collect(),
-        )
+        if numbers.len() < 2 {
+            return Err(SolveError::new("At least two numbers are needed to mix"));
+        }
+        if !numbers.contains(&0) {
+            return Err(SolveError::new("The file does not contain a 0"));
+        }
+        Ok(numbers.into_iter().enumerate().collect())
     }
 
-    fn silver(&self, input: &Self::Input) -> Option<Self::Output> {
+    fn silver(
+        &self,
+        input: &Self::Input,
+        _params: &Self::Params,
+    ) -> Result<Self::Output, SolveError> {
         let mut input_clone = input.clone();
         for i in 0..input_clone.len() {
             mixing(&mut input_clone, i);
@@ -57,18 +75,18 @@ impl Assignment for Solution {
         let x = get_wrap_around(&input_clone, zero_pos + 1000).unwrap();
         let y = get_wrap_around(&input_clone, zero_pos + 2000).unwrap();
         let z = get_wrap_around(&input_clone, zero_pos + 3000).unwrap();
-        Some((x.1 + y.1 + z.1).into())
+        Ok((x.1 + y.1 + z.1).into())
     }
 
-    fn gold(&self, input: &Self::Input) -> Option<Self::Output> {
+    fn gold(&self, input: &Self::Input, params: &Self::Params) -> Result<Self::Output, SolveError> {
         let mut input_clone = input.clone();
-        let decryption_key = 811589153;
+        let decryption_key = params.decryption_key;
         input_clone = input_clone
             .iter()
             .map(|(i, value)| (*i, value * decryption_key))
             .collect::<Vec<(usize, i64)>>();
 
-        for _ in 0..10 {
+        for _ in 0..params.mixes {
             for i in 0..input_clone.len() {
                 mixing(&mut input_clone, i);
             }
@@ -80,13 +98,14 @@ impl Assignment for Solution {
         let x = get_wrap_around(&input_clone, zero_pos + 1000).unwrap();
         let y = get_wrap_around(&input_clone, zero_pos + 2000).unwrap();
         let z = get_wrap_around(&input_clone, zero_pos + 3000).unwrap();
-        Some((x.1 + y.1 + z.1).into())
+        Ok((x.1 + y.1 + z.1).into())
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::testing::assignment_tests;
 
     static TEST_INPUT: &str = "1
 2
@@ -96,19 +115,8 @@ mod tests {
 0
 4";
 
-    #[test]
-    fn test_silver() {
-        let sol = Solution::new();
-        let input = sol.parse_input(TEST_INPUT);
-        let result = sol.silver(&input.unwrap()).unwrap();
-        assert_eq!(result, 3)
-    }
-
-    #[test]
-    fn test_gold() {
-        let sol = Solution::new();
-        let input = sol.parse_input(TEST_INPUT);
-        let result = sol.gold(&input.unwrap()).unwrap();
-        assert_eq!(result, 1623178306)
+    assignment_tests! {
+        silver: TEST_INPUT => 3;
+        gold: TEST_INPUT => 1623178306;
     }
 }
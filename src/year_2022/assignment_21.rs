@@ -1,6 +1,10 @@
 use std::collections::HashMap;
 
-use crate::{Assignment, Output};
+use crate::{
+    error::SolveError,
+    parse::{self, Line},
+    Assignment, Output,
+};
 
 #[derive(Debug, Clone)]
 pub enum Operation {
@@ -16,45 +20,35 @@ pub enum Monkey {
     Number(String, i64),
 }
 
-impl std::str::FromStr for Monkey {
-    type Err = String;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let name = s[..4].to_string();
-
-        let digit_finder = regex::Regex::new(r"-?\d+").unwrap();
-        if digit_finder.is_match(s) {
-            let digit = digit_finder
-                .captures(s)
-                .unwrap()
-                .get(0)
-                .unwrap()
-                .as_str()
-                .parse()
-                .unwrap();
-            Ok(Monkey::Number(name, digit))
-        } else {
-            let expression_finder = regex::Regex::new(
-                r"^\w+: (?P<monkey_left>\w+) (?P<expression>.) (?P<monkey_right>\w+)",
-            )
-            .unwrap();
-            let caps = expression_finder.captures(s).unwrap();
-            let monkey_left = caps.name("monkey_left").unwrap().as_str().to_string();
-            let monkey_right = caps.name("monkey_right").unwrap().as_str().to_string();
-            let expression = match caps.name("expression").unwrap().as_str() {
-                "+" => Operation::Plus,
-                "-" => Operation::Minus,
-                "*" => Operation::Multiply,
-                "/" => Operation::Divide,
-                _ => panic!("Could not parse expression in string {}", s),
-            };
-
-            Ok(Monkey::Expression(
-                name,
-                monkey_left,
-                monkey_right,
-                expression,
-            ))
+impl Monkey {
+    fn parse(line: Line) -> Result<Monkey, SolveError> {
+        let (name, job) = line.split_once(": ")?;
+        let name = name.to_string();
+
+        let words = job.split(' ').collect::<Vec<&str>>();
+        match words[..] {
+            [number] => Ok(Monkey::Number(name, line.parse(number)?)),
+            [monkey_left, expression, monkey_right] => {
+                let expression = match expression {
+                    "+" => Operation::Plus,
+                    "-" => Operation::Minus,
+                    "*" => Operation::Multiply,
+                    "/" => Operation::Divide,
+                    _ => {
+                        return Err(
+                            line.error(expression, "Unknown operation, expected +, -, * or /")
+                        )
+                    }
+                };
+
+                Ok(Monkey::Expression(
+                    name,
+                    monkey_left.to_string(),
+                    monkey_right.to_string(),
+                    expression,
+                ))
+            }
+            _ => Err(line.error(job, "Expected a number or an operation on two monkeys")),
         }
     }
 }
@@ -99,29 +93,51 @@ impl Solution {
 impl Assignment for Solution {
     type Input = HashMap<String, Monkey>;
     type Output = Output;
-
-    fn parse_input(&self, input: &str) -> Option<Self::Input> {
-        Some(
-            input
-                .lines()
-                .map(|monkey| monkey.parse().unwrap())
-                .map(|monkey| match &monkey {
-                    Monkey::Expression(name, _, _, _) => (name.clone(), monkey),
-                    Monkey::Number(name, _) => (name.clone(), monkey),
-                })
-                .collect(),
-        )
+    type Params = ();
+
+    fn parse_input(&self, input: &str) -> Result<Self::Input, SolveError> {
+        let parsed = parse::lines(input)
+            .map(|line| Ok((line, Monkey::parse(line)?)))
+            .collect::<Result<Vec<_>, SolveError>>()?;
+        let monkeys = parsed
+            .iter()
+            .map(|(_, monkey)| match monkey {
+                Monkey::Expression(name, _, _, _) => (name.clone(), monkey.clone()),
+                Monkey::Number(name, _) => (name.clone(), monkey.clone()),
+            })
+            .collect::<HashMap<String, Monkey>>();
+
+        for (line, monkey) in &parsed {
+            if let Monkey::Expression(_, left, right, _) = monkey {
+                if let Some(unknown) = [left, right]
+                    .into_iter()
+                    .find(|name| !monkeys.contains_key(*name))
+                {
+                    return Err(line.error(unknown, "Unknown monkey"));
+                }
+            }
+        }
+        if !monkeys.contains_key("root") {
+            return Err(SolveError::new("There is no monkey called root"));
+        }
+        Ok(monkeys)
     }
 
-    fn silver(&self, monkeys: &Self::Input) -> Option<Self::Output> {
-        Some(
-            solve_monkey_equation(&String::from("root"), monkeys)
-                .0
-                .into(),
-        )
+    fn silver(
+        &self,
+        monkeys: &Self::Input,
+        _params: &Self::Params,
+    ) -> Result<Self::Output, SolveError> {
+        Ok(solve_monkey_equation(&String::from("root"), monkeys)
+            .0
+            .into())
     }
 
-    fn gold(&self, monkeys: &Self::Input) -> Option<Self::Output> {
+    fn gold(
+        &self,
+        monkeys: &Self::Input,
+        _params: &Self::Params,
+    ) -> Result<Self::Output, SolveError> {
         fn what_to_shout(name: &String, monkeys: &HashMap<String, Monkey>, should_be: i64) -> i64 {
             if name == &String::from("humn") {
                 should_be
@@ -172,12 +188,14 @@ impl Assignment for Solution {
             let right_solve = solve_monkey_equation(right, monkeys);
 
             if left_solve.1 {
-                Some(what_to_shout(left, monkeys, right_solve.0).into())
+                Ok(what_to_shout(left, monkeys, right_solve.0).into())
             } else {
-                Some(what_to_shout(right, monkeys, left_solve.0).into())
+                Ok(what_to_shout(right, monkeys, left_solve.0).into())
             }
         } else {
-            Some((-1).into())
+            Err(SolveError::new(
+                "The root monkey does not compare two monkeys",
+            ))
         }
     }
 }
@@ -185,6 +203,7 @@ impl Assignment for Solution {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::testing::assignment_tests;
 
     static TEST_INPUT: &str = "root: pppw + sjmn
 dbpl: 5
@@ -202,19 +221,27 @@ lgvd: ljgn * ptdq
 drzm: hmdt - zczc
 hmdt: 32";
 
-    #[test]
-    fn test_silver() {
-        let sol = Solution::new();
-        let input = sol.parse_input(TEST_INPUT);
-        let result = sol.silver(&input.unwrap()).unwrap();
-        assert_eq!(result, 152)
+    assignment_tests! {
+        silver: TEST_INPUT => 152;
+        gold: TEST_INPUT => 301;
     }
 
     #[test]
-    fn test_gold() {
+    fn test_malformed_monkey() {
         let sol = Solution::new();
-        let input = sol.parse_input(TEST_INPUT);
-        let result = sol.gold(&input.unwrap()).unwrap();
-        assert_eq!(result, 301)
+        let err = sol
+            .parse_input("root: pppw + sjmn\npppw: 4\nsj 2")
+            .err()
+            .unwrap();
+        let location = err.location.unwrap();
+
+        assert_eq!((location.line, location.column), (3, 1));
+        assert_eq!(location.text, "sj 2");
+
+        let err = sol.parse_input("root: pppw + sjmn\npppw: 4").err().unwrap();
+        let location = err.location.unwrap();
+
+        assert_eq!((location.line, location.column), (1, 14));
+        assert_eq!(location.text, "sjmn");
     }
 }
@@ -0,0 +1,639 @@
+use crate::{
+    error::SolveError,
+    params::params,
+    parse::{self, Line},
+    Assignment, Output,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Tile {
+    None,
+    Empty,
+    Wall,
+}
+
+impl Tile {
+    fn parse(line: Line, index: usize, c: char) -> Result<Tile, SolveError> {
+        match c {
+            ' ' => Ok(Tile::None),
+            '.' => Ok(Tile::Empty),
+            '#' => Ok(Tile::Wall),
+            c => Err(line.error(
+                &line.text[index..index + c.len_utf8()],
+                "Not a tile, expected ' ', '.' or '#'",
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Board {
+    tiles: Vec<Vec<Tile>>,
+    player_coords: (usize, usize),
+    facing: Direction,
+    cube: Option<Vec<Vec<Vec<Vec<Tile>>>>>,
+    cube_player_coords: (usize, usize, usize, usize),
+}
+
+impl Board {
+    fn get_movement_vector(&self) -> (i32, i32) {
+        match self.facing {
+            Direction::Right => (0, 1),
+            Direction::Down => (1, 0),
+            Direction::Left => (0, -1),
+            Direction::Up => (-1, 0),
+        }
+    }
+
+    fn move_player(&mut self) -> Result<(), SolveError> {
+        match self.cube.clone() {
+            None => {
+                let move_vector = self.get_movement_vector();
+
+                let v_length = self.tiles.len();
+                let h_length = self.tiles[self.player_coords.0].len();
+
+                let len = match self.facing {
+                    Direction::Right | Direction::Left => h_length,
+                    Direction::Down | Direction::Up => v_length,
+                };
+
+                for i in 1..len {
+                    let mut new_y = (self.player_coords.0 as i32 + (i as i32 * move_vector.0))
+                        % v_length as i32;
+                    let mut new_x = (self.player_coords.1 as i32 + (i as i32 * move_vector.1))
+                        % h_length as i32;
+
+                    if new_y.is_negative() {
+                        new_y += v_length as i32;
+                    }
+                    if new_x.is_negative() {
+                        new_x += h_length as i32;
+                    }
+
+                    let new_coord = (new_y, new_x);
+
+                    match self.tiles[new_coord.0 as usize].get(new_coord.1 as usize) {
+                        None => continue,
+                        Some(tile) => match tile {
+                            Tile::None => continue,
+                            Tile::Wall => break,
+                            Tile::Empty => {
+                                self.player_coords = (new_coord.0 as usize, new_coord.1 as usize);
+                                break;
+                            }
+                        },
+                    }
+                }
+            }
+            Some(cube) => {
+                let len = cube[0][0].len();
+
+                let mut new_cube_y = self.cube_player_coords.0;
+                let mut new_cube_x = self.cube_player_coords.1;
+                let mut new_y = self.cube_player_coords.2;
+                let mut new_x = self.cube_player_coords.3;
+                let mut new_facing = self.facing.clone();
+
+                match self.facing {
+                    Direction::Right => {
+                        if new_x < len - 1 {
+                            new_x += 1;
+                        } else {
+                            match (new_cube_y, new_cube_x) {
+                                (0, _) => {
+                                    new_cube_y = 1;
+                                    new_cube_x = 1;
+                                    new_y = 0;
+                                    new_x = len - 1 - self.cube_player_coords.2;
+                                    new_facing = Direction::Down;
+                                }
+                                (1, _) => {
+                                    new_cube_x = (new_cube_x + 1) % 4;
+                                    new_x = 0;
+                                }
+                                (2, _) => {
+                                    new_cube_y = 1;
+                                    new_cube_x = 1;
+                                    new_y = len - 1;
+                                    new_x = self.cube_player_coords.2;
+                                    new_facing = Direction::Up;
+                                }
+                                _ => return Err(off_the_cube()),
+                            }
+                        }
+                    }
+                    Direction::Left => {
+                        if new_x > 0 {
+                            new_x -= 1;
+                        } else {
+                            match (new_cube_y, new_cube_x) {
+                                (0, _) => {
+                                    new_cube_y = 1;
+                                    new_cube_x = 3;
+                                    new_y = 0;
+                                    new_x = self.cube_player_coords.2;
+                                    new_facing = Direction::Down;
+                                }
+                                (1, _) => {
+                                    new_cube_x = (4 + new_cube_x - 1) % 4;
+                                    new_x = len - 1;
+                                }
+                                (2, _) => {
+                                    new_cube_y = 1;
+                                    new_cube_x = 1;
+                                    new_y = len - 1;
+                                    new_x = len - 1 - self.cube_player_coords.2;
+                                    new_facing = Direction::Up;
+                                }
+                                _ => return Err(off_the_cube()),
+                            }
+                        }
+                    }
+                    Direction::Down => {
+                        if new_y < len - 1 {
+                            new_y += 1;
+                        } else {
+                            match (new_cube_y, new_cube_x) {
+                                (0, 0) | (1, 0) => {
+                                    new_cube_y += 1;
+                                    new_y = 0;
+                                }
+                                (1, 1) => {
+                                    new_cube_y += 1;
+                                    new_cube_x = 0;
+                                    new_y = self.cube_player_coords.3;
+                                    new_x = len - 1;
+                                    new_facing = Direction::Left
+                                }
+                                (1, 2) => {
+                                    new_cube_y += 1;
+                                    new_cube_x = 0;
+                                    new_y = len - 1;
+                                    new_x = len - 1 - self.cube_player_coords.3;
+                                    new_facing = Direction::Up
+                                }
+                                (1, 3) => {
+                                    new_cube_y += 1;
+                                    new_cube_x = 0;
+                                    new_y = len - 1 - self.cube_player_coords.3;
+                                    new_x = 0;
+                                    new_facing = Direction::Right
+                                }
+                                (2, 0) => {
+                                    new_cube_y = 1;
+                                    new_cube_x = 2;
+                                    new_y = len - 1;
+                                    new_x = len - 1 - self.cube_player_coords.3;
+                                    new_facing = Direction::Up
+                                }
+                                _ => return Err(off_the_cube()),
+                            }
+                        }
+                    }
+                    Direction::Up => {
+                        if new_y > 0 {
+                            new_y -= 1;
+                        } else {
+                            match (new_cube_y, new_cube_x) {
+                                (0, 0) => {
+                                    new_cube_y += 1;
+                                    new_cube_x = 2;
+                                    new_y = 0;
+                                    new_x = len - 1 - self.cube_player_coords.3;
+                                    new_facing = Direction::Down
+                                }
+                                (1, 1) => {
+                                    new_cube_y -= 1;
+                                    new_cube_x = 0;
+                                    new_y = len - 1 - self.cube_player_coords.3;
+                                    new_x = len - 1;
+                                    new_facing = Direction::Left
+                                }
+                                (1, 2) => {
+                                    new_cube_y -= 1;
+                                    new_cube_x = 0;
+                                    new_y = 0;
+                                    new_x = len - 1 - self.cube_player_coords.3;
+                                    new_facing = Direction::Down
+                                }
+                                (1, 3) => {
+                                    new_cube_y -= 1;
+                                    new_cube_x = 0;
+                                    new_y = self.cube_player_coords.3;
+                                    new_x = 0;
+                                    new_facing = Direction::Right
+                                }
+                                (1, 0) | (2, 0) => {
+                                    new_cube_y -= 1;
+                                    new_y = len - 1;
+                                }
+                                _ => return Err(off_the_cube()),
+                            }
+                        }
+                    }
+                }
+
+                let tile = cube
+                    .get(new_cube_y)
+                    .and_then(|faces| faces.get(new_cube_x))
+                    .and_then(|face| face.get(new_y))
+                    .and_then(|row| row.get(new_x));
+                match tile {
+                    None | Some(Tile::None) => return Err(off_the_cube()),
+                    Some(Tile::Wall) => {}
+                    Some(Tile::Empty) => {
+                        self.cube_player_coords = (new_cube_y, new_cube_x, new_y, new_x);
+                        self.facing = new_facing;
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn process_instruction(&mut self, instruction: &Instruction) -> Result<(), SolveError> {
+        match instruction {
+            Instruction::Move(to_move) => {
+                for _ in 0..*to_move {
+                    self.move_player()?;
+                }
+            }
+            _ => {
+                self.facing = self.facing.rotate(instruction);
+            }
+        }
+        Ok(())
+    }
+
+    fn get_score(&self) -> i32 {
+        (1 + self.player_coords.0 as i32) * 1000
+            + (1 + self.player_coords.1 as i32) * 4
+            + self.facing.get_score()
+    }
+
+    fn convert_to_cube(&mut self, plane_size: usize) -> Result<(), SolveError> {
+        let does_not_fold = || {
+            SolveError::new(format!(
+                "The board does not fold into a cube with faces of {}",
+                plane_size
+            ))
+            .with_hint("set the size of the faces with --param face_size=<size>")
+        };
+        if plane_size == 0
+            || self.tiles.len() > 4 * plane_size
+            || self.tiles.iter().any(|row| row.len() > 4 * plane_size)
+        {
+            return Err(does_not_fold());
+        }
+
+        let mut cube = vec![vec![vec![vec![Tile::None; plane_size]; plane_size]; 4]; 4];
+
+        for (i, row) in self.tiles.iter().enumerate() {
+            let i_cube = i / plane_size;
+            for (j, tile) in row.iter().enumerate() {
+                let j_cube = j / plane_size;
+                cube[i_cube][j_cube][i % plane_size][j % plane_size] = tile.clone();
+            }
+        }
+
+        let cube_coords = self
+            .tiles
+            .chunks(plane_size)
+            .enumerate()
+            .flat_map(|(i, rows)| {
+                rows[0]
+                    .chunks(plane_size)
+                    .enumerate()
+                    .map(|(j, tiles)| {
+                        if tiles[0] == Tile::None {
+                            (i, j, false)
+                        } else {
+                            (i, j, true)
+                        }
+                    })
+                    .filter(|(_, _, bool)| *bool)
+                    .map(|(i, j, _)| (i, j))
+                    .collect::<Vec<(usize, usize)>>()
+            })
+            .collect::<Vec<(usize, usize)>>();
+
+        let top_coords = *cube_coords.first().ok_or_else(does_not_fold)?;
+        if top_coords.0 + 2 >= cube.len() {
+            return Err(does_not_fold());
+        }
+        let top = &cube[top_coords.0][top_coords.1];
+        let mut center = cube[top_coords.0 + 1].clone();
+        let bottom = &cube[top_coords.0 + 2][top_coords.1];
+
+        let mut added_to_left = 0;
+
+        for (y, x) in cube_coords {
+            if x == top_coords.1 || y == top_coords.0 + 1 {
+                continue;
+            }
+
+            match (y < top_coords.0 + 1, x < top_coords.1) {
+                (true, true) => {
+                    let to_add = translate_plane(
+                        &cube[y][x],
+                        TranslateDirection::CounterClockwise,
+                        y.abs_diff(top_coords.0 + 1),
+                    );
+                    center.splice(0..1, Vec::from([to_add]));
+                    added_to_left += 1;
+                }
+                (true, false) => center.push(translate_plane(
+                    &cube[y][x],
+                    TranslateDirection::Clockwise,
+                    y.abs_diff(top_coords.0 + 1),
+                )),
+                (false, true) => {
+                    let to_add = translate_plane(
+                        &cube[y][x],
+                        TranslateDirection::Clockwise,
+                        y.abs_diff(top_coords.0 + 1),
+                    );
+                    center.splice(0..1, Vec::from([to_add]));
+                    added_to_left += 1;
+                }
+                (false, false) => center.push(translate_plane(
+                    &cube[y][x],
+                    TranslateDirection::CounterClockwise,
+                    y.abs_diff(top_coords.0 + 1),
+                )),
+            }
+        }
+
+        for _ in 0..(added_to_left + top_coords.1) {
+            let to_add = center.remove(0);
+            center.push(to_add);
+        }
+
+        center = center
+            .into_iter()
+            .filter(|plane| plane[0][0] != Tile::None)
+            .collect::<Vec<Vec<Vec<Tile>>>>();
+
+        self.cube = Some(vec![vec![top.clone()], center, vec![bottom.clone()]]);
+        Ok(())
+    }
+}
+
+impl Board {
+    fn parse(lines: &[Line]) -> Result<Board, SolveError> {
+        let tiles = lines
+            .iter()
+            .map(|line| {
+                line.text
+                    .char_indices()
+                    .map(|(index, c)| Tile::parse(*line, index, c))
+                    .collect::<Result<Vec<Tile>, _>>()
+            })
+            .collect::<Result<Vec<Vec<Tile>>, _>>()?;
+
+        let mut player_coords = (0, 0);
+
+        'outer: for (i, row) in tiles.iter().enumerate() {
+            for (j, tile) in row.iter().enumerate() {
+                if *tile == Tile::Empty {
+                    player_coords = (i, j);
+                    break 'outer;
+                }
+            }
+        }
+
+        Ok(Board {
+            tiles,
+            player_coords,
+            facing: Direction::Right,
+            cube: None,
+            cube_player_coords: (0, 0, 0, 0),
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum Direction {
+    Right,
+    Down,
+    Left,
+    Up,
+}
+
+impl Direction {
+    fn rotate(&self, instruction: &Instruction) -> Direction {
+        match (self, instruction) {
+            (Direction::Right, Instruction::RotateClockwise) => Direction::Down,
+            (Direction::Right, Instruction::RotateCounterClockwise) => Direction::Up,
+            (Direction::Down, Instruction::RotateClockwise) => Direction::Left,
+            (Direction::Down, Instruction::RotateCounterClockwise) => Direction::Right,
+            (Direction::Left, Instruction::RotateClockwise) => Direction::Up,
+            (Direction::Left, Instruction::RotateCounterClockwise) => Direction::Down,
+            (Direction::Up, Instruction::RotateClockwise) => Direction::Right,
+            (Direction::Up, Instruction::RotateCounterClockwise) => Direction::Left,
+            (_, Instruction::Move(_)) => self.clone(),
+        }
+    }
+
+    fn get_score(&self) -> i32 {
+        match self {
+            Direction::Right => 0,
+            Direction::Down => 1,
+            Direction::Left => 2,
+            Direction::Up => 3,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum Instruction {
+    Move(i32),
+    RotateClockwise,
+    RotateCounterClockwise,
+}
+
+fn parse_instructions(line: Line) -> Result<Vec<Instruction>, SolveError> {
+    use pom::char_class::*;
+    use pom::parser::*;
+
+    let turn = sym(b'L').map(|_| Instruction::RotateCounterClockwise)
+        | sym(b'R').map(|_| Instruction::RotateClockwise);
+    let move_size = || {
+        is_a(digit).repeat(1..).map(|digits| {
+            Instruction::Move(
+                digits
+                    .iter()
+                    .fold(0i32, |result, digit| result * 10 + (digit - b'0') as i32),
+            )
+        })
+    };
+
+    let parser =
+        (move_size() + (turn + move_size()).repeat(1..)).map(|(first_step, turns_and_steps)| {
+            let mut instructions = vec![first_step];
+            instructions.append(
+                &mut turns_and_steps
+                    .into_iter()
+                    .flat_map(|(i1, i2)| vec![i1, i2])
+                    .collect::<Vec<Instruction>>(),
+            );
+
+            instructions
+        });
+
+    if let Some((index, c)) = line
+        .text
+        .char_indices()
+        .find(|(_, c)| !c.is_ascii_digit() && *c != 'L' && *c != 'R')
+    {
+        return Err(line.error(
+            &line.text[index..index + c.len_utf8()],
+            "Not an instruction, expected a number, L or R",
+        ));
+    }
+
+    parser
+        .parse(line.text.as_bytes())
+        .map_err(|err| line.error(line.text, err.to_string()))
+}
+
+fn off_the_cube() -> SolveError {
+    SolveError::new("Walked off the cube while following the path")
+}
+
+enum TranslateDirection {
+    Clockwise,
+    CounterClockwise,
+}
+
+fn translate_plane(
+    plane: &[Vec<Tile>],
+    direction: TranslateDirection,
+    times: usize,
+) -> Vec<Vec<Tile>> {
+    let mut new_plane = plane.to_vec();
+
+    for _ in 0..times {
+        let mut temp_plane = new_plane.clone();
+        for (i, row) in new_plane.iter().enumerate() {
+            for (j, tile) in row.iter().enumerate() {
+                match direction {
+                    TranslateDirection::Clockwise => {
+                        temp_plane[j][new_plane.len() - 1 - i] = tile.clone()
+                    }
+                    TranslateDirection::CounterClockwise => {
+                        temp_plane[new_plane.len() - 1 - j][i] = tile.clone()
+                    }
+                }
+            }
+        }
+        new_plane = temp_plane
+    }
+
+    new_plane
+}
+
+params! {
+    pub struct Params {
+        /// The length of an edge of a face of the cube
+        face_size: usize = 50,
+    }
+}
+
+pub struct Solution {}
+
+impl Solution {
+    pub fn new() -> Solution {
+        Solution {}
+    }
+}
+
+impl Assignment for Solution {
+    type Input = (Board, Vec<Instruction>);
+    type Output = Output;
+    type Params = Params;
+
+    fn parse_input(&self, input: &str) -> Result<Self::Input, SolveError> {
+        let lines = parse::lines(input).collect::<Vec<_>>();
+        let split = lines
+            .iter()
+            .position(|line| line.text.is_empty())
+            .ok_or_else(|| {
+                SolveError::new("Expected an empty line between the board and the path")
+            })?;
+        let path = lines
+            .get(split + 1)
+            .ok_or_else(|| SolveError::new("Expected a path after the board"))?;
+
+        Ok((Board::parse(&lines[..split])?, parse_instructions(*path)?))
+    }
+
+    fn silver(
+        &self,
+        (board, instructions): &Self::Input,
+        _params: &Self::Params,
+    ) -> Result<Self::Output, SolveError> {
+        let mut board_clone = board.clone();
+        let instructions_clone = instructions.clone();
+
+        for instruction in &instructions_clone {
+            board_clone.process_instruction(instruction)?;
+        }
+
+        Ok(board_clone.get_score().into())
+    }
+
+    fn gold(
+        &self,
+        (board, instructions): &Self::Input,
+        params: &Self::Params,
+    ) -> Result<Self::Output, SolveError> {
+        let mut board_clone = board.clone();
+        let instructions_clone = instructions.clone();
+
+        board_clone.convert_to_cube(params.face_size)?;
+
+        for instruction in &instructions_clone {
+            board_clone.process_instruction(instruction)?;
+        }
+
+        Err(SolveError::new("Walking around the cube is not solved yet"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::assignment_tests;
+
+    static TEST_INPUT: &str = "        ...#
+        .#..
+        #...
+        ....
+...#.......#
+........#...
+..#....#....
+..........#.
+        ...#....
+        .....#..
+        .#......
+        ......#.
+
+10R5L5R10L4R5L5";
+
+    assignment_tests! {
+        params: Params { face_size: 4 };
+        silver: TEST_INPUT => 6032;
+        gold: TEST_INPUT => 5031;
+    }
+
+    #[test]
+    fn test_gold_wrong_face_size() {
+        let sol = Solution::new();
+        let input = sol.parse_input(TEST_INPUT).unwrap();
+        let err = sol.gold(&input, &Params { face_size: 2 }).unwrap_err();
+        assert!(err.to_string().contains("does not fold"), "{}", err);
+    }
+}
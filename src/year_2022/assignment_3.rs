@@ -1,4 +1,4 @@
-use crate::{Assignment, Output};
+use crate::{error::SolveError, parse, Assignment, Output};
 
 #[derive(Debug)]
 pub struct Bag {
@@ -86,49 +86,60 @@ impl Solution {
 impl Assignment for Solution {
     type Input = Vec<Bag>;
     type Output = Output;
+    type Params = ();
 
-    fn parse_input(&self, input: &str) -> Option<Self::Input> {
+    fn parse_input(&self, input: &str) -> Result<Self::Input, SolveError> {
         let mut result = Vec::new();
-        for line in input.lines() {
-            result.push(Bag::new(line));
+        for line in parse::lines(input) {
+            if let Some((index, c)) = line
+                .text
+                .char_indices()
+                .find(|(_, c)| !c.is_ascii_alphabetic())
+            {
+                return Err(line.error(&line.text[index..index + c.len_utf8()], "Not an item"));
+            }
+            result.push(Bag::new(line.text));
         }
-        Some(result)
+        Ok(result)
     }
 
-    fn silver(&self, input: &Self::Input) -> Option<Self::Output> {
-        Some(input.iter().map(Bag::both_sides).sum::<i32>().into())
+    fn silver(
+        &self,
+        input: &Self::Input,
+        _params: &Self::Params,
+    ) -> Result<Self::Output, SolveError> {
+        Ok(input.iter().map(Bag::both_sides).sum::<i32>().into())
     }
 
-    fn gold(&self, input: &Self::Input) -> Option<Self::Output> {
-        Some(
-            input
-                .chunks(3)
-                .map(|bags| bags[0].get_group_badge(&[&bags[1], &bags[2]]))
-                .sum::<i32>()
-                .into(),
-        )
+    fn gold(
+        &self,
+        input: &Self::Input,
+        _params: &Self::Params,
+    ) -> Result<Self::Output, SolveError> {
+        if input.len() % 3 != 0 {
+            return Err(SolveError::new(format!(
+                "{} bags can't be split into groups of three",
+                input.len()
+            )));
+        }
+
+        Ok(input
+            .chunks(3)
+            .map(|bags| bags[0].get_group_badge(&[&bags[1], &bags[2]]))
+            .sum::<i32>()
+            .into())
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::testing::assignment_tests;
 
     static TEST_INPUT: &str = "vJrwpWtwJgWrhcsFMMfFFhFp\njqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL\nPmmdzqPrVvPwwTWBwg\nwMqvLMZHhHMvwLHjbvcjnnSBnvTQFn\nttgJtRGJQctTZtZT\nCrZsJsPPZsGzwwsLwLmpwMDw";
 
-    #[test]
-    fn test_silver() {
-        let sol = Solution::new();
-        let input = sol.parse_input(TEST_INPUT);
-        let result = sol.silver(&input.unwrap()).unwrap();
-        assert_eq!(result, 157)
-    }
-
-    #[test]
-    fn test_gold() {
-        let sol = Solution::new();
-        let input = sol.parse_input(TEST_INPUT);
-        let result = sol.gold(&input.unwrap()).unwrap();
-        assert_eq!(result, 70)
+    assignment_tests! {
+        silver: TEST_INPUT => 157;
+        gold: TEST_INPUT => 70;
     }
 }
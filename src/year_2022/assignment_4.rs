@@ -1,4 +1,4 @@
-use crate::{Assignment, Output};
+use crate::{error::SolveError, parse, Assignment, Output};
 
 #[derive(Debug)]
 pub struct Elf {
@@ -33,61 +33,60 @@ impl Solution {
 impl Assignment for Solution {
     type Input = Vec<(Elf, Elf)>;
     type Output = Output;
+    type Params = ();
 
-    fn parse_input(&self, input: &str) -> Option<Self::Input> {
+    fn parse_input(&self, input: &str) -> Result<Self::Input, SolveError> {
         let mut result = Vec::new();
-        for line in input.lines() {
-            let (first, second) = line.split_once(',').unwrap();
+        for line in parse::lines(input) {
+            let (first, second) = line.split_once(",")?;
 
-            let (first_lower, first_upper) = first.split_once('-').unwrap();
-            let first_elf = Elf::new(first_lower.parse().unwrap(), first_upper.parse().unwrap());
+            let (first_lower, first_upper) = line.split(first, "-")?;
+            let first_elf = Elf::new(line.parse(first_lower)?, line.parse(first_upper)?);
 
-            let (second_lower, second_upper) = second.split_once('-').unwrap();
-            let second_elf = Elf::new(second_lower.parse().unwrap(), second_upper.parse().unwrap());
+            let (second_lower, second_upper) = line.split(second, "-")?;
+            let second_elf = Elf::new(line.parse(second_lower)?, line.parse(second_upper)?);
 
             result.push((first_elf, second_elf));
         }
-        Some(result)
+        Ok(result)
     }
 
-    fn silver(&self, input: &Self::Input) -> Option<Self::Output> {
+    fn silver(
+        &self,
+        input: &Self::Input,
+        _params: &Self::Params,
+    ) -> Result<Self::Output, SolveError> {
         let result = input
             .iter()
             .map(|(first, second)| first.is_engulfed(second) || second.is_engulfed(first))
             .filter(|b| *b)
             .count() as i32;
-        Some(result.into())
+        Ok(result.into())
     }
 
-    fn gold(&self, input: &Self::Input) -> Option<Self::Output> {
+    fn gold(
+        &self,
+        input: &Self::Input,
+        _params: &Self::Params,
+    ) -> Result<Self::Output, SolveError> {
         let result = input
             .iter()
             .map(|(first, second)| first.overlaps(second))
             .filter(|b| *b)
             .count() as i32;
-        Some(result.into())
+        Ok(result.into())
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::testing::assignment_tests;
 
     static TEST_INPUT: &str = "2-4,6-8\n2-3,4-5\n5-7,7-9\n2-8,3-7\n6-6,4-6\n2-6,4-8";
 
-    #[test]
-    fn test_silver() {
-        let sol = Solution::new();
-        let input = sol.parse_input(TEST_INPUT);
-        let result = sol.silver(&input.unwrap()).unwrap();
-        assert_eq!(result, 2)
-    }
-
-    #[test]
-    fn test_gold() {
-        let sol = Solution::new();
-        let input = sol.parse_input(TEST_INPUT);
-        let result = sol.gold(&input.unwrap()).unwrap();
-        assert_eq!(result, 4)
+    assignment_tests! {
+        silver: TEST_INPUT => 2;
+        gold: TEST_INPUT => 4;
     }
 }
@@ -0,0 +1,182 @@
+use regex::Regex;
+use std::collections::VecDeque;
+
+use crate::{error::SolveError, parse, Assignment, Output};
+
+#[derive(Debug, Clone)]
+pub struct Crate {
+    id: char,
+}
+
+impl Crate {
+    fn new(c: char) -> Crate {
+        Crate { id: c }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Instruction {
+    amount: usize,
+    from: usize,
+    to: usize,
+}
+
+impl Instruction {
+    fn new(amount: usize, from: usize, to: usize) -> Instruction {
+        Instruction { amount, from, to }
+    }
+}
+
+fn empty_stack(index: usize) -> SolveError {
+    SolveError::new(format!("Stack {} ran out of crates", index + 1))
+}
+
+fn top_crates(stacks: &VecDeque<VecDeque<Crate>>) -> Result<String, SolveError> {
+    stacks
+        .iter()
+        .enumerate()
+        .map(|(index, stack)| stack.back().map(|c| c.id).ok_or_else(|| empty_stack(index)))
+        .collect()
+}
+
+pub struct Solution {}
+
+impl Solution {
+    pub fn new() -> Solution {
+        Solution {}
+    }
+}
+
+impl Assignment for Solution {
+    type Input = (VecDeque<VecDeque<Crate>>, VecDeque<Instruction>);
+    type Output = Output;
+    type Params = ();
+
+    fn parse_input(&self, input: &str) -> Result<Self::Input, SolveError> {
+        let mut stacks = VecDeque::new();
+        let mut instructions = VecDeque::new();
+        let mut convert_stacks = true;
+        let total_stacks = (input.lines().next().unwrap_or_default().len() + 1) / 4;
+
+        let instruction_re =
+            Regex::new(r"^move (?P<amount>\d+) from (?P<from>\d+) to (?P<to>\d+)$").unwrap();
+
+        for _ in 0..total_stacks {
+            stacks.push_back(VecDeque::new());
+        }
+
+        for line in parse::lines(input) {
+            if line.text.is_empty() {
+                convert_stacks = false;
+                continue;
+            }
+
+            if convert_stacks {
+                for (index, chunks) in line
+                    .text
+                    .chars()
+                    .collect::<Vec<char>>()
+                    .chunks(4)
+                    .enumerate()
+                {
+                    if chunks[0] == '[' {
+                        let stack: &mut VecDeque<Crate> =
+                            stacks.get_mut(index).ok_or_else(|| {
+                                line.error(
+                                    &line.text[index * 4..],
+                                    "More stacks than on the first line",
+                                )
+                                .with_hint(
+                                    "every line of the drawing should be as wide as the first",
+                                )
+                            })?;
+                        stack.push_front(Crate::new(chunks.get(1).copied().unwrap_or(' ')));
+                    }
+                }
+            } else {
+                let cap = instruction_re.captures(line.text).ok_or_else(|| {
+                    line.error(line.text, "This is not a move instruction")
+                        .with_hint("instructions look like 'move <amount> from <stack> to <stack>'")
+                })?;
+                let stack = |name: &str| -> Result<usize, SolveError> {
+                    let text = cap.name(name).map_or("", |m| m.as_str());
+                    match line.parse::<usize>(text)? {
+                        number @ 1.. if number <= total_stacks => Ok(number - 1),
+                        _ => Err(line.error(text, format!("There is no stack {}", text))),
+                    }
+                };
+                instructions.push_back(Instruction::new(
+                    line.parse(cap.name("amount").map_or("", |m| m.as_str()))?,
+                    stack("from")?,
+                    stack("to")?,
+                ))
+            }
+        }
+
+        Ok((stacks, instructions))
+    }
+
+    fn silver(
+        &self,
+        (s, instructions): &Self::Input,
+        _params: &Self::Params,
+    ) -> Result<Self::Output, SolveError> {
+        let mut stacks = s.clone();
+
+        for instruction in instructions {
+            for _ in 0..instruction.amount {
+                let cr = stacks[instruction.from]
+                    .pop_back()
+                    .ok_or_else(|| empty_stack(instruction.from))?;
+                stacks[instruction.to].push_back(cr);
+            }
+        }
+
+        Ok(top_crates(&stacks)?.into())
+    }
+
+    fn gold(
+        &self,
+        (s, instructions): &Self::Input,
+        _params: &Self::Params,
+    ) -> Result<Self::Output, SolveError> {
+        let mut stacks = s.clone();
+
+        for instruction in instructions {
+            let stack_length = stacks[instruction.from]
+                .len()
+                .checked_sub(instruction.amount)
+                .ok_or_else(|| empty_stack(instruction.from))?;
+            let mut crates = stacks[instruction.from].split_off(stack_length);
+            stacks[instruction.to].append(&mut crates);
+        }
+
+        Ok(top_crates(&stacks)?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::assignment_tests;
+
+    static TEST_INPUT: &str = "    [D]    \n[N] [C]    \n[Z] [M] [P]\n 1   2   3 \n\nmove 1 from 2 to 1\nmove 3 from 1 to 3\nmove 2 from 2 to 1\nmove 1 from 1 to 2";
+
+    assignment_tests! {
+        silver: TEST_INPUT => "CMZ";
+        gold: TEST_INPUT => "MCD";
+    }
+
+    #[test]
+    fn test_malformed_move() {
+        let sol = Solution::new();
+        let input = TEST_INPUT.replace("move 3 from 1", "move 3 form 1");
+        let err = sol.parse_input(&input).err().unwrap();
+
+        assert_eq!(err.location.as_ref().unwrap().line, 7);
+        assert!(err
+            .snippet("input")
+            .contains("7 | move 3 form 1 to 3\n  | ^^^^^^^^^^^^^^^^^^"));
+        assert!(err.hint.is_some());
+    }
+}
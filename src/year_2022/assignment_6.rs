@@ -1,4 +1,4 @@
-use crate::{Assignment, Output};
+use crate::{error::SolveError, Assignment, Output};
 
 use std::collections::VecDeque;
 
@@ -19,12 +19,17 @@ fn is_unique(input: &VecDeque<&char>) -> bool {
 impl Assignment for Solution {
     type Input = VecDeque<char>;
     type Output = Output;
+    type Params = ();
 
-    fn parse_input(&self, input: &str) -> Option<Self::Input> {
-        Some(input.chars().collect())
+    fn parse_input(&self, input: &str) -> Result<Self::Input, SolveError> {
+        Ok(input.chars().collect())
     }
 
-    fn silver(&self, input: &Self::Input) -> Option<Self::Output> {
+    fn silver(
+        &self,
+        input: &Self::Input,
+        _params: &Self::Params,
+    ) -> Result<Self::Output, SolveError> {
         let mut sliding_window = VecDeque::new();
         let mut result: usize = 0;
 
@@ -42,10 +47,17 @@ impl Assignment for Solution {
                 sliding_window.push_back(c)
             }
         }
-        Some((result as u32).into())
+        match result {
+            0 => Err(SolveError::new("No start-of-packet marker found")),
+            result => Ok((result as u32).into()),
+        }
     }
 
-    fn gold(&self, input: &Self::Input) -> Option<Self::Output> {
+    fn gold(
+        &self,
+        input: &Self::Input,
+        _params: &Self::Params,
+    ) -> Result<Self::Output, SolveError> {
         let mut sliding_window = VecDeque::new();
         let mut result: usize = 0;
 
@@ -63,13 +75,17 @@ impl Assignment for Solution {
                 sliding_window.push_back(c)
             }
         }
-        Some((result as u32).into())
+        match result {
+            0 => Err(SolveError::new("No start-of-message marker found")),
+            result => Ok((result as u32).into()),
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::testing::assignment_tests;
 
     static TEST_INPUT: [(&str, i32, i32); 5] = [
         ("mjqjpqmgbljsphdztnvjfqwrcgsmlb", 7, 19),
@@ -79,23 +95,7 @@ mod tests {
         ("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 11, 26),
     ];
 
-    #[test]
-    fn test_silver() {
-        let sol = Solution::new();
-        for (raw, expected, _) in TEST_INPUT {
-            let input = sol.parse_input(&raw.to_owned());
-            let result = sol.silver(&input.unwrap()).unwrap();
-            assert_eq!(result, expected)
-        }
-    }
-
-    #[test]
-    fn test_gold() {
-        let sol = Solution::new();
-        for (raw, _, expected) in TEST_INPUT {
-            let input = sol.parse_input(&raw.to_owned());
-            let result = sol.gold(&input.unwrap()).unwrap();
-            assert_eq!(result, expected)
-        }
+    assignment_tests! {
+        examples: TEST_INPUT;
     }
 }
@@ -1,4 +1,9 @@
-use crate::{Assignment, Output};
+use crate::{
+    error::SolveError,
+    params::params,
+    parse::{self, Line},
+    Assignment, Output,
+};
 use std::collections::VecDeque;
 
 #[derive(Debug, Clone)]
@@ -101,24 +106,35 @@ enum Command {
 }
 
 #[derive(Debug)]
-pub struct Operation {
+pub struct Operation<'a> {
     input: Command,
-    output: Vec<String>,
+    output: Vec<Line<'a>>,
 }
 
-impl Operation {
-    fn new(cmd: Command) -> Operation {
+impl<'a> Operation<'a> {
+    fn new(cmd: Command) -> Operation<'a> {
         Operation {
             input: cmd,
             output: Vec::new(),
         }
     }
 
-    fn add_output(&mut self, output: String) {
+    fn add_output(&mut self, output: Line<'a>) {
         self.output.push(output);
     }
 }
 
+params! {
+    pub struct Params {
+        /// Directories below this size are summed up
+        small_dir: i32 = 100_000,
+        /// The total space on the device
+        disk_size: i32 = 70_000_000,
+        /// The unused space the update needs
+        needed_space: i32 = 30_000_000,
+    }
+}
+
 pub struct Solution {}
 
 impl Solution {
@@ -130,13 +146,14 @@ impl Solution {
 impl Assignment for Solution {
     type Input = Directory;
     type Output = Output;
+    type Params = Params;
 
-    fn parse_input(&self, input: &str) -> Option<Self::Input> {
+    fn parse_input(&self, input: &str) -> Result<Self::Input, SolveError> {
         let mut operations = VecDeque::new();
         let mut current_operation = Operation::new(Command::Cd(String::from("/")));
 
-        for line in input.lines().skip(1) {
-            let words: Vec<&str> = line.split(' ').collect();
+        for line in parse::lines(input).skip(1) {
+            let words: Vec<&str> = line.text.split(' ').collect();
             match words.first() {
                 Some(&"$") => match words.get(1) {
                     Some(&"ls") => {
@@ -144,13 +161,18 @@ impl Assignment for Solution {
                         current_operation = Operation::new(Command::Ls)
                     }
                     Some(&"cd") => {
+                        let name = words.get(2).ok_or_else(|| {
+                            line.error(line.text, "Expected a directory to cd into")
+                        })?;
                         operations.push_back(current_operation);
-                        current_operation =
-                            Operation::new(Command::Cd(words.get(2).unwrap().to_string()))
+                        current_operation = Operation::new(Command::Cd(name.to_string()))
+                    }
+                    command => {
+                        let command = command.copied().unwrap_or(line.text);
+                        return Err(line.error(command, "Unknown command, expected cd or ls"));
                     }
-                    _ => panic!("Something went wrong when parsing {}", line),
                 },
-                _ => current_operation.add_output(line.to_string()),
+                _ => current_operation.add_output(line),
             }
         }
         operations.push_back(current_operation);
@@ -163,17 +185,16 @@ impl Assignment for Solution {
             match op.input {
                 Command::Ls => {
                     for line in op.output {
-                        let words: Vec<&str> = line.split(' ').collect();
-                        let name = words.get(1).unwrap().to_string();
-                        match words.first() {
-                            Some(&"dir") => {
-                                let new_dir = Directory::new(name);
+                        let (kind, name) = line.split_once(" ")?;
+                        match kind {
+                            "dir" => {
+                                let new_dir = Directory::new(name.to_string());
                                 root.add_directory(new_dir, &current_dir);
                                 // self.add_directory(new_dir)
                             }
-                            _ => {
-                                let size: i32 = words.first().unwrap().parse().unwrap();
-                                root.add_file(File::new(name, size), &current_dir);
+                            size => {
+                                let size: i32 = line.parse(size)?;
+                                root.add_file(File::new(name.to_string(), size), &current_dir);
                             }
                         }
                     }
@@ -189,55 +210,60 @@ impl Assignment for Solution {
             }
         }
 
-        Some(root)
+        Ok(root)
     }
 
-    fn silver(&self, root: &Self::Input) -> Option<Self::Output> {
-        Some(
-            root.find_dir_with_lower_size(100000)
-                .into_iter()
-                .sum::<i32>()
-                .into(),
-        )
+    fn silver(
+        &self,
+        root: &Self::Input,
+        params: &Self::Params,
+    ) -> Result<Self::Output, SolveError> {
+        Ok(root
+            .find_dir_with_lower_size(params.small_dir)
+            .into_iter()
+            .sum::<i32>()
+            .into())
     }
 
-    fn gold(&self, root: &Self::Input) -> Option<Self::Output> {
-        let max_size = 70_000_000;
-        let to_free = 30_000_000;
+    fn gold(&self, root: &Self::Input, params: &Self::Params) -> Result<Self::Output, SolveError> {
+        let max_size = params.disk_size;
+        let to_free = params.needed_space;
         let total_used = root.get_total_size();
         let minimal_removal = total_used - max_size + to_free;
         let mut sizes = root.get_all_dir_sizes();
 
-        sizes.sort_by(|a, b| a.1.cmp(&b.1));
+        sizes.sort_by_key(|a| a.1);
         let to_remove = sizes
             .into_iter()
             .find(|(_, s)| s >= &minimal_removal)
-            .unwrap();
+            .ok_or_else(|| SolveError::new(format!("No directory frees up {}", minimal_removal)))?;
 
-        println!("{:?}", to_remove);
-        Some((to_remove.1).into())
+        Ok((to_remove.1).into())
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::testing::assignment_tests;
 
     static TEST_INPUT: &str = "$ cd /\n$ ls\ndir a\n14848514 b.txt\n8504156 c.dat\ndir d\n$ cd a\n$ ls\ndir e\n29116 f\n2557 g\n62596 h.lst\n$ cd e\n$ ls\n584 i\n$ cd ..\n$ cd ..\n$ cd d\n$ ls\n4060174 j\n8033020 d.log\n5626152 d.ext\n7214296 k";
 
-    #[test]
-    fn test_silver() {
-        let sol = Solution::new();
-        let input = sol.parse_input(TEST_INPUT);
-        let result = sol.silver(&input.unwrap()).unwrap();
-        assert_eq!(result, 95437)
+    assignment_tests! {
+        silver: TEST_INPUT => 95437;
+        gold: TEST_INPUT => 24933642;
     }
 
     #[test]
-    fn test_gold() {
+    fn test_unknown_command() {
         let sol = Solution::new();
-        let input = sol.parse_input(TEST_INPUT);
-        let result = sol.gold(&input.unwrap()).unwrap();
-        assert_eq!(result, 24933642)
+        let err = sol
+            .parse_input("$ cd /\n$ ls\n14848514 b.txt\n$ rm b.txt\n")
+            .err()
+            .unwrap();
+        let location = err.location.unwrap();
+
+        assert_eq!((location.line, location.column), (4, 3));
+        assert_eq!(location.text, "rm");
     }
 }
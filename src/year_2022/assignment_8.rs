@@ -0,0 +1,142 @@
+use crate::{error::SolveError, parse, Assignment, Output};
+
+pub struct Solution {}
+
+impl Solution {
+    pub fn new() -> Solution {
+        Solution {}
+    }
+}
+
+fn is_visible(grid: &[Vec<u32>], i: usize, j: usize) -> bool {
+    let tree = grid[i][j];
+
+    let visible = [
+        grid[..i].iter().all(|row| row[j] < tree),
+        grid[i][..j].iter().all(|t| *t < tree),
+        grid[i][j + 1..].iter().all(|t| *t < tree),
+        grid[i + 1..].iter().all(|row| row[j] < tree),
+    ];
+
+    visible.into_iter().any(|b| b)
+}
+
+fn scenic_score(grid: &[Vec<u32>], i: usize, j: usize) -> u32 {
+    let size = grid.len();
+    let mut visible_trees = [i, j, size - (j + 1), size - (i + 1)];
+    let tree = grid[i][j];
+
+    if let Some(x) = grid[..i].iter().rposition(|row| row[j] >= tree) {
+        visible_trees[0] = i - x;
+    }
+    if let Some(x) = grid[i][..j].iter().rposition(|t| *t >= tree) {
+        visible_trees[1] = j - x;
+    }
+    if let Some(x) = grid[i][j + 1..].iter().position(|t| *t >= tree) {
+        visible_trees[2] = x + 1;
+    }
+    if let Some(x) = grid[i + 1..].iter().position(|row| row[j] >= tree) {
+        visible_trees[3] = x + 1;
+    }
+
+    visible_trees
+        .into_iter()
+        .fold(1, |acc, amount| acc * (amount as u32))
+}
+
+impl Assignment for Solution {
+    type Input = Vec<Vec<u32>>;
+    type Output = Output;
+    type Params = ();
+
+    fn parse_input(&self, input: &str) -> Result<Self::Input, SolveError> {
+        let mut result = Vec::new();
+        for line in parse::lines(input) {
+            let row = line
+                .text
+                .char_indices()
+                .map(|(index, c)| {
+                    c.to_digit(10).ok_or_else(|| {
+                        line.error(&line.text[index..index + c.len_utf8()], "Not a tree height")
+                    })
+                })
+                .collect::<Result<Vec<u32>, _>>()?;
+            if result
+                .first()
+                .is_some_and(|first: &Vec<u32>| first.len() != row.len())
+            {
+                return Err(line.error(line.text, "Rows have different lengths"));
+            }
+            result.push(row);
+        }
+
+        if result
+            .first()
+            .is_some_and(|first| first.len() != result.len())
+        {
+            return Err(SolveError::new("The forest is not square"));
+        }
+        Ok(result)
+    }
+
+    fn silver(
+        &self,
+        input: &Self::Input,
+        _params: &Self::Params,
+    ) -> Result<Self::Output, SolveError> {
+        let size = input.len();
+        let mut count = 0;
+
+        for (i, v) in input.iter().enumerate() {
+            for (j, _) in v.iter().enumerate() {
+                if i == 0 || j == 0 || i == size - 1 || j == size - 1 {
+                    count += 1;
+                    continue;
+                }
+
+                if is_visible(input, i, j) {
+                    count += 1;
+                }
+            }
+        }
+
+        Ok((count).into())
+    }
+
+    fn gold(
+        &self,
+        input: &Self::Input,
+        _params: &Self::Params,
+    ) -> Result<Self::Output, SolveError> {
+        let size = input.len();
+        let mut max_scenic_score = 0;
+
+        for (i, v) in input.iter().enumerate() {
+            for (j, _) in v.iter().enumerate() {
+                if i == 0 || j == 0 || i == size - 1 || j == size - 1 {
+                    continue;
+                }
+
+                let score = scenic_score(input, i, j);
+                if score > max_scenic_score {
+                    max_scenic_score = score;
+                }
+            }
+        }
+
+        Ok((max_scenic_score).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::assignment_tests;
+
+    static TEST_INPUT: &str = "30373\n25512\n65332\n33549\n35390";
+
+    assignment_tests! {
+        silver: TEST_INPUT => 21;
+        gold: TEST_INPUT => 8;
+    }
+}
@@ -1,8 +1,8 @@
+use std::collections::HashSet;
 use std::hash::Hash;
 use std::ops::Add;
-use std::{collections::HashSet, str::FromStr};
 
-use crate::{Assignment, Output};
+use crate::{error::SolveError, parse, Assignment, Output};
 
 pub enum Direction {
     Up(i32),
@@ -11,26 +11,9 @@ pub enum Direction {
     Left(i32),
 }
 
-impl FromStr for Direction {
-    type Err = String;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (direction, magnitude) = s.split_once(' ').unwrap();
-        let magnitude = magnitude.parse::<i32>().unwrap();
-
-        match direction {
-            "L" => Ok(Direction::Left(magnitude)),
-            "R" => Ok(Direction::Right(magnitude)),
-            "U" => Ok(Direction::Up(magnitude)),
-            "D" => Ok(Direction::Down(magnitude)),
-            _ => Err(format!("unknown direction {}", direction)),
-        }
-    }
-}
-
-impl Into<(Coord, i32)> for &Direction {
-    fn into(self) -> (Coord, i32) {
-        match self {
+impl From<&Direction> for (Coord, i32) {
+    fn from(val: &Direction) -> Self {
+        match val {
             Direction::Left(times) => (Coord::new(-1, 0), *times),
             Direction::Right(times) => (Coord::new(1, 0), *times),
             Direction::Up(times) => (Coord::new(0, 1), *times),
@@ -106,23 +89,46 @@ fn calculate_knot_movement(input: &Vec<Direction>, knot_size: usize) -> usize {
 impl Assignment for Solution {
     type Input = Vec<Direction>;
     type Output = Output;
-
-    fn parse_input(&self, input: &str) -> Option<Self::Input> {
-        Some(input.lines().map(|l| l.parse().unwrap()).collect())
+    type Params = ();
+
+    fn parse_input(&self, input: &str) -> Result<Self::Input, SolveError> {
+        parse::lines(input)
+            .map(|line| {
+                let (direction, magnitude) = line.split_once(" ")?;
+                let magnitude = line.parse::<i32>(magnitude)?;
+
+                match direction {
+                    "L" => Ok(Direction::Left(magnitude)),
+                    "R" => Ok(Direction::Right(magnitude)),
+                    "U" => Ok(Direction::Up(magnitude)),
+                    "D" => Ok(Direction::Down(magnitude)),
+                    _ => Err(line.error(direction, "Unknown direction, expected L, R, U or D")),
+                }
+            })
+            .collect()
     }
 
-    fn silver(&self, input: &Self::Input) -> Option<Self::Output> {
-        Some((calculate_knot_movement(input, 2) as i32).into())
+    fn silver(
+        &self,
+        input: &Self::Input,
+        _params: &Self::Params,
+    ) -> Result<Self::Output, SolveError> {
+        Ok((calculate_knot_movement(input, 2) as i32).into())
     }
 
-    fn gold(&self, input: &Self::Input) -> Option<Self::Output> {
-        Some((calculate_knot_movement(input, 10) as i32).into())
+    fn gold(
+        &self,
+        input: &Self::Input,
+        _params: &Self::Params,
+    ) -> Result<Self::Output, SolveError> {
+        Ok((calculate_knot_movement(input, 10) as i32).into())
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::testing::assignment_tests;
 
     static TEST_INPUT: &str = "R 4
 U 4
@@ -141,27 +147,8 @@ D 10
 L 25
 U 20";
 
-    #[test]
-    fn test_silver() {
-        let sol = Solution::new();
-        let input = sol.parse_input(TEST_INPUT);
-        let result = sol.silver(&input.unwrap()).unwrap();
-        assert_eq!(result, 13)
-    }
-
-    #[test]
-    fn test_gold() {
-        let sol = Solution::new();
-        let input = sol.parse_input(TEST_INPUT);
-        let result = sol.gold(&input.unwrap()).unwrap();
-        assert_eq!(result, 1)
-    }
-
-    #[test]
-    fn test_large_gold() {
-        let sol = Solution::new();
-        let input = sol.parse_input(&LARGE_TEST_INPUT.to_owned());
-        let result = sol.gold(&input.unwrap()).unwrap();
-        assert_eq!(result, 36)
+    assignment_tests! {
+        silver: TEST_INPUT => 13;
+        gold: TEST_INPUT => 1, LARGE_TEST_INPUT => 36;
     }
 }
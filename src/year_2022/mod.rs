@@ -0,0 +1,27 @@
+use crate::registry::register_days;
+
+register_days! {
+    2022;
+    1 => assignment_1, "Calorie Counting";
+    2 => assignment_2, "Rock Paper Scissors";
+    3 => assignment_3, "Rucksack Reorganization";
+    4 => assignment_4, "Camp Cleanup";
+    5 => assignment_5, "Supply Stacks";
+    6 => assignment_6, "Tuning Trouble";
+    7 => assignment_7, "No Space Left On Device";
+    8 => assignment_8, "Treetop Tree House";
+    9 => assignment_9, "Rope Bridge";
+    10 => assignment_10, "Cathode-Ray Tube";
+    11 => assignment_11, "Monkey in the Middle";
+    12 => assignment_12, "Hill Climbing Algorithm";
+    13 => assignment_13, "Distress Signal";
+    14 => assignment_14, "Regolith Reservoir";
+    15 => assignment_15, "Beacon Exclusion Zone";
+    16 => assignment_16, "Proboscidea Volcanium";
+    17 => assignment_17, "Pyroclastic Flow";
+    18 => assignment_18, "Boiling Boulders";
+    19 => assignment_19, "Not Enough Minerals";
+    20 => assignment_20, "Grove Positioning System";
+    21 => assignment_21, "Monkey Math";
+    22 => assignment_22, "Monkey Map";
+}
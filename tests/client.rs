@@ -0,0 +1,244 @@
+use std::{path::PathBuf, time::Duration};
+
+use advent_2022::{
+    answers::AnswerStore, client::AocClient, error::AdventError, examples::ExampleStore,
+    mock::MockServer, submission::SubmissionResult,
+};
+
+static INPUT: &str = "1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000\n";
+
+fn data_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("advent_client_{}_{}", std::process::id(), name));
+    let _ = std::fs::remove_dir_all(&dir);
+    dir
+}
+
+fn client(name: &str, session: Option<&str>) -> AocClient {
+    let base_url = MockServer::new("secret")
+        .with_puzzle(1, INPUT, "24000", "45000")
+        .with_penalty(Duration::from_secs(90))
+        .start()
+        .unwrap();
+
+    AocClient::new(&base_url, session.map(String::from), data_dir(name))
+}
+
+#[test]
+fn test_get_input() {
+    let client = client("get_input", Some("secret"));
+    assert_eq!(client.get_input(2022, 1).unwrap(), INPUT);
+    assert!(client.data_dir().join("2022").join("input_1.txt").exists());
+
+    let offline = AocClient::new("http://127.0.0.1:1", None, client.data_dir().to_owned());
+    assert_eq!(offline.get_input(2022, 1).unwrap(), INPUT);
+}
+
+#[test]
+fn test_get_input_starting_with_angle_bracket() {
+    let jets = "<<><>>><<<>>><<<><<<>><>><<>>\n";
+    let base_url = MockServer::new("secret")
+        .with_puzzle(17, jets, "3068", "1514285714288")
+        .start()
+        .unwrap();
+    let client = AocClient::new(&base_url, Some(String::from("secret")), data_dir("jets"));
+
+    assert_eq!(client.get_input(2022, 17).unwrap(), jets);
+}
+
+#[test]
+fn test_send_answer_encoded() {
+    let base_url = MockServer::new("secret")
+        .with_puzzle(5, INPUT, "CMZ", "M&M +1")
+        .start()
+        .unwrap();
+    let client = AocClient::new(&base_url, Some(String::from("secret")), data_dir("encoded"));
+
+    assert_eq!(
+        client.send_answer(2022, 5, 1, "CMZ").unwrap(),
+        SubmissionResult::Correct
+    );
+    assert_eq!(
+        client.send_answer(2022, 5, 2, "M&M +1").unwrap(),
+        SubmissionResult::Correct
+    );
+}
+
+#[test]
+fn test_get_input_errors() {
+    let client = client("get_input_errors", Some("expired"));
+    assert!(matches!(
+        client.get_input(2022, 1),
+        Err(AdventError::NotLoggedIn)
+    ));
+    assert!(!client.data_dir().join("2022").join("input_1.txt").exists());
+
+    let client = AocClient::new(
+        client.base_url(),
+        Some(String::from("secret")),
+        data_dir("locked"),
+    );
+    assert!(matches!(
+        client.get_input(2022, 2),
+        Err(AdventError::NotUnlocked(2))
+    ));
+
+    let client = AocClient::new(client.base_url(), None, data_dir("no_session"));
+    assert!(matches!(
+        client.get_input(2022, 1),
+        Err(AdventError::MissingSession)
+    ));
+}
+
+#[test]
+fn test_send_answer() {
+    let client = client("send_answer", Some("secret"));
+
+    assert_eq!(client.solved_levels(2022, 1).unwrap(), 0);
+    assert_eq!(
+        client.send_answer(2022, 1, 2, 45000).unwrap(),
+        SubmissionResult::WrongLevel
+    );
+    assert_eq!(
+        client.send_answer(2022, 1, 1, 24000).unwrap(),
+        SubmissionResult::Correct
+    );
+    assert_eq!(client.solved_levels(2022, 1).unwrap(), 1);
+    let answers = AnswerStore::load(&client.year_dir(2022).unwrap()).unwrap();
+    assert_eq!(answers.get(1).unwrap().silver.as_deref(), Some("24000"));
+    assert_eq!(answers.get(1).unwrap().gold, None);
+    assert!(matches!(
+        client.send_answer(2022, 1, 1, 24001),
+        Err(AdventError::Refused(_))
+    ));
+
+    assert_eq!(
+        client.send_answer(2022, 1, 2, 50000).unwrap(),
+        SubmissionResult::TooHigh
+    );
+    assert_eq!(
+        client.send_answer(2022, 1, 2, 45000).unwrap(),
+        SubmissionResult::RateLimited(Duration::from_secs(90))
+    );
+    assert!(matches!(
+        client.send_answer(2022, 1, 2, 60000),
+        Err(AdventError::Refused(_))
+    ));
+}
+
+#[test]
+fn test_send_answer_session() {
+    let client = client("send_answer_session", Some("expired"));
+    assert!(matches!(
+        client.send_answer(2022, 1, 1, 24000),
+        Err(AdventError::NotLoggedIn)
+    ));
+}
+
+#[test]
+fn test_gold_solved_elsewhere() {
+    let client = client("solved_elsewhere", Some("secret"));
+    client.send_answer(2022, 1, 1, 24000).unwrap();
+    client.send_answer(2022, 1, 2, 45000).unwrap();
+
+    let elsewhere = AocClient::new(
+        client.base_url(),
+        Some(String::from("secret")),
+        data_dir("elsewhere"),
+    );
+    assert_eq!(
+        elsewhere.send_answer(2022, 1, 2, 45000).unwrap(),
+        SubmissionResult::AlreadySolved
+    );
+    let answers = AnswerStore::load(&elsewhere.year_dir(2022).unwrap()).unwrap();
+    assert_eq!(answers.get(1).unwrap().level(1), Some("24000"));
+    assert_eq!(answers.get(1).unwrap().level(2), Some("45000"));
+}
+
+#[test]
+fn test_seed_answers() {
+    let client = client("seed_answers", Some("secret"));
+    assert_eq!(client.seed_answers(2022, 1).unwrap(), Default::default());
+
+    client.send_answer(2022, 1, 1, 24000).unwrap();
+    let seeded = AocClient::new(
+        client.base_url(),
+        Some(String::from("secret")),
+        data_dir("seeded"),
+    );
+    assert_eq!(
+        seeded.seed_answers(2022, 1).unwrap().silver.as_deref(),
+        Some("24000")
+    );
+    let answers = AnswerStore::load(&seeded.year_dir(2022).unwrap()).unwrap();
+    assert_eq!(answers.get(1).unwrap().level(1), Some("24000"));
+    assert_eq!(answers.get(1).unwrap().level(2), None);
+}
+
+#[test]
+fn test_fetch_example() {
+    let client = client("fetch_example", Some("secret"));
+
+    let example = client.fetch_example(2022, 1).unwrap();
+    assert_eq!(example.input, INPUT);
+    assert_eq!(example.silver.as_deref(), Some("24000"));
+    assert_eq!(example.gold, None);
+
+    client.send_answer(2022, 1, 1, 24000).unwrap();
+    client.fetch_example(2022, 1).unwrap();
+    let examples = ExampleStore::load(&client.year_dir(2022).unwrap(), 1).unwrap();
+    assert_eq!(examples.len(), 1);
+    assert_eq!(examples.get(1).unwrap().gold.as_deref(), Some("45000"));
+}
+
+#[test]
+fn test_description() {
+    let client = client("description", Some("secret"));
+
+    let markdown = client.description(2022, 1).unwrap();
+    assert!(markdown.starts_with("## "));
+    assert!(!markdown.contains("Part Two"));
+
+    let offline = AocClient::new("http://127.0.0.1:1", None, client.data_dir().to_owned());
+    assert_eq!(offline.description(2022, 1).unwrap(), markdown);
+
+    client.send_answer(2022, 1, 1, 24000).unwrap();
+    let unlocked = offline.description(2022, 1).unwrap();
+    assert!(unlocked.contains("## --- Part Two ---"));
+    assert!(unlocked.contains("*`45000`*"));
+}
+
+#[test]
+fn test_legacy_layout() {
+    let client = client("legacy", None);
+    std::fs::create_dir_all(client.data_dir()).unwrap();
+    advent_2022::input::write_cached(&client.data_dir().join("input_1.txt"), INPUT).unwrap();
+
+    assert_eq!(client.get_input(2022, 1).unwrap(), INPUT);
+    assert!(client.data_dir().join("2022").join("input_1.txt").exists());
+    assert!(!client.data_dir().join("input_1.txt").exists());
+}
+
+#[test]
+fn test_legacy_layout_untouched() {
+    let client = client("legacy_untouched", None);
+    std::fs::create_dir_all(client.data_dir()).unwrap();
+    advent_2022::input::write_cached(&client.data_dir().join("input_1.txt"), INPUT).unwrap();
+
+    std::fs::remove_file(client.data_dir().join("input_1.txt.sha256")).unwrap();
+
+    assert_eq!(client.existing_year_dir(2022), client.data_dir());
+    assert_eq!(client.cached_input(2022, 1).as_deref(), Some(INPUT));
+    assert!(!client.data_dir().join("input_1.txt.sha256").exists());
+    assert!(client.data_dir().join("input_1.txt").exists());
+    assert!(!client.data_dir().join("2022").exists());
+}
+
+#[test]
+fn test_cache_without_hash() {
+    let offline = AocClient::new("http://127.0.0.1:1", None, data_dir("without_hash"));
+    let year_dir = offline.year_dir(2022).unwrap();
+    std::fs::write(year_dir.join("input_1.txt"), INPUT).unwrap();
+
+    assert_eq!(offline.get_input(2022, 1).unwrap(), INPUT);
+    assert!(year_dir.join("input_1.txt.sha256").exists());
+}
@@ -0,0 +1,41 @@
+//! Solve every example that was extracted from the puzzle pages with `advent_2022 example <day>`
+//! and check it gives the answers of the puzzle description. Days without examples are skipped.
+
+use advent_2022::{
+    answers::Verdict,
+    client::{AocClient, DEFAULT_BASE_URL},
+    examples::ExampleStore,
+    registry, summary,
+};
+
+#[test]
+fn test_stored_examples() {
+    let client = AocClient::new(DEFAULT_BASE_URL, None, advent_2022::data_dir().unwrap());
+    let mut failures = Vec::new();
+
+    for day in registry::days() {
+        let examples = ExampleStore::load(&client.existing_year_dir(day.year), day.day).unwrap();
+
+        for (index, example) in examples.iter().enumerate() {
+            let verdict = match summary::catch_panic(|| {
+                day.solve_with(example.input.clone(), &example.params)
+            }) {
+                Ok(Ok(result)) => Verdict::check(&example.answers(), &result),
+                Ok(Err(err)) => Verdict::Failed(err.to_string()),
+                Err(reason) => Verdict::Failed(reason),
+            };
+
+            if verdict.is_failure() {
+                failures.push(format!(
+                    "{} day {} example {}: {}",
+                    day.year,
+                    day.day,
+                    index + 1,
+                    verdict
+                ));
+            }
+        }
+    }
+
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}